@@ -0,0 +1,1059 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::ControlFlow;
+use serde_json::{Map, Value};
+use crate::schema::definitions::{Definitions, MAX_REF_DEPTH};
+use crate::schema_type::advanced_type::all_of_type::AllOfTypeError;
+use crate::schema_type::advanced_type::any_of_type::AnyOfTypeError;
+use crate::schema_type::advanced_type::array_type::{ArrayConstraints, ArrayType, ArrayTypeError};
+use crate::schema_type::advanced_type::if_type::IfTypeError;
+use crate::schema_type::advanced_type::not_type::NotTypeError;
+use crate::schema_type::advanced_type::object_type::object_constraint::ObjectConstraint;
+use crate::schema_type::advanced_type::object_type::{AdditionalProperties, ObjectType, ObjectTypeError};
+use crate::schema_type::advanced_type::one_of_type::OneOfTypeError;
+use crate::schema_type::advanced_type::record_type::{RecordKeyType, RecordType, RecordTypeError};
+use crate::schema_type::advanced_type::ref_type::RefTypeError;
+use crate::schema_type::advanced_type::tagged_type::TaggedTypeError;
+use crate::schema_type::advanced_type::tuple_type::{TupleError, TupleType};
+use crate::schema_type::advanced_type::{AdvancedType, AdvancedTypeValidationError};
+use crate::schema_type::error_message::{ErrorMessage, WithErrorMessage};
+use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::traits::validator::Validator;
+
+/// A [SchemaType] that has been prepared for validating many values. Shorthands are resolved into
+/// the types they stand for and references are resolved against the definitions once, so
+/// validating a value does not clone parts of the schema or look up definitions by name. Values
+/// that match the schema are validated without allocating, apart from what the validators of
+/// strings, numbers and custom types need themselves.
+///
+/// Validating gives the same result as validating against the [SchemaType] in a [ValidationScope]
+/// with the definitions, including the errors. Changes to the definitions after compiling are not
+/// picked up.
+///
+/// [ValidationScope]: crate::schema::validation_scope::ValidationScope
+#[derive(Debug, Clone)]
+pub struct CompiledSchema {
+    root: Node,
+    definitions: Vec<Node>,
+}
+
+impl CompiledSchema {
+    /// Compiles the schema type, resolving references against the provided definitions.
+    /// References to unknown definitions result in an error when they are validated, like they do
+    /// for the [SchemaType]. Objects that don't configure [AdditionalProperties] themselves reject
+    /// unknown keys if `strict_objects` is true, see [Schema::set_strict_objects].
+    ///
+    /// [Schema::set_strict_objects]: crate::schema::Schema::set_strict_objects
+    pub fn new(schema_type: &SchemaType, definitions: &Definitions, strict_objects: bool) -> Self {
+        let compiler = Compiler {
+            names: definitions.iter()
+                .enumerate()
+                .map(|(index, (name, _))| (name.as_str(), index))
+                .collect(),
+            strict_objects,
+        };
+
+        CompiledSchema {
+            root: Node::compile(schema_type, &compiler),
+            definitions: definitions.iter()
+                .map(|(_, definition)| Node::compile(definition, &compiler))
+                .collect(),
+        }
+    }
+}
+
+impl Validator for CompiledSchema {
+    type E = SchemaTypeValidationError;
+
+    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+        self.root.validate_node(value, Scope {
+            definitions: &self.definitions,
+            depth: 0,
+            shared: None,
+        })
+    }
+}
+
+/// The indexes of the definitions by name and the policy for objects, which are used while
+/// compiling the nodes.
+struct Compiler<'a> {
+    names: HashMap<&'a str, usize>,
+    strict_objects: bool,
+}
+
+/// The compiled definitions, together with the number of references that have been followed
+/// and the keys that are shared while validating the current value.
+#[derive(Clone, Copy)]
+struct Scope<'a> {
+    definitions: &'a [Node],
+    depth: usize,
+    shared: Option<SharedFrame<'a>>,
+}
+
+/// Keys that are shared for the current value, like the [ValidationScope] keeps them for the
+/// schema types. The frames are linked, as all keys that are shared for the value apply when
+/// combinators are nested.
+///
+/// [ValidationScope]: crate::schema::validation_scope::ValidationScope
+#[derive(Clone, Copy)]
+struct SharedFrame<'a> {
+    keys: SharedNodes<'a>,
+    parent: Option<&'a SharedFrame<'a>>,
+}
+
+/// The compiled counterpart of [SharedKeys].
+///
+/// [SharedKeys]: crate::schema_type::advanced_type::object_type::SharedKeys
+#[derive(Clone, Copy)]
+enum SharedNodes<'a> {
+    Declared(&'a [Node]),
+    If(&'a IfNode),
+    Object(&'a ObjectNode),
+    Key(&'a str),
+}
+
+impl SharedNodes<'_> {
+    fn contains(&self, key: &str, target_map: &Map<String, Value>, scope: Scope) -> bool {
+        match self {
+            SharedNodes::Declared(nodes) => nodes.iter()
+                .any(|node| node.declares_key(key, target_map, scope)),
+            SharedNodes::If(if_node) => if_node.declares_key(key, target_map, scope),
+            SharedNodes::Object(object_node) => object_node.is_known_key(key, target_map, scope),
+            SharedNodes::Key(shared) => *shared == key,
+        }
+    }
+}
+
+impl<'a> Scope<'a> {
+    /// Runs the provided closure with the keys shared for the current value, like
+    /// [ValidationScope::share] does for the schema types.
+    ///
+    /// [ValidationScope::share]: crate::schema::validation_scope::ValidationScope::share
+    fn share<R>(self, keys: SharedNodes, f: impl FnOnce(Scope) -> R) -> R {
+        let parent = self.shared;
+
+        f(Scope {
+            shared: Some(SharedFrame { keys, parent: parent.as_ref() }),
+            ..self
+        })
+    }
+
+    /// Returns the scope for a value nested in the current value, which the shared keys don't
+    /// apply to.
+    fn nested(self) -> Self {
+        Scope {
+            shared: None,
+            ..self
+        }
+    }
+
+    fn is_shared(&self, key: &str, target_map: &Map<String, Value>) -> bool {
+        let mut frame = self.shared.as_ref();
+
+        while let Some(SharedFrame { keys, parent }) = frame {
+            if keys.contains(key, target_map, *self) {
+                return true;
+            }
+
+            frame = *parent;
+        }
+
+        false
+    }
+
+    /// Returns the definition the reference points to, together with the scope to validate it in.
+    fn resolve(self, ref_node: &RefNode) -> Result<(&'a Node, Scope<'a>), RefTypeError> {
+        let Some(definition) = ref_node.index.and_then(|index| self.definitions.get(index)) else {
+            return Err(RefTypeError::UnknownDefinition(ref_node.name.to_string()));
+        };
+
+        if self.depth >= MAX_REF_DEPTH {
+            return Err(RefTypeError::MaxDepthExceeded(MAX_REF_DEPTH));
+        }
+
+        let scope = Scope {
+            depth: self.depth + 1,
+            ..self
+        };
+
+        Ok((definition, scope))
+    }
+}
+
+/// Like [Validator], but for compiled nodes, which resolve references through the [Scope].
+trait NodeValidator {
+    type E;
+
+    fn validate_node(&self, value: &Value, scope: Scope) -> Result<(), Self::E>;
+}
+
+/// A compiled [SchemaType].
+#[derive(Debug, Clone)]
+enum Node {
+    /// Schema types without nested schemas, which are validated by the schema type itself.
+    Leaf(SchemaType),
+    Field(Box<Node>, Option<ErrorMessage>),
+    Advanced(Box<AdvancedNode>, Option<ErrorMessage>),
+}
+
+/// A compiled [AdvancedType] that has nested schemas. Shorthands compile to the advanced type they
+/// stand for. The errors that include the original schema, like those of [AdvancedType::AnyOf],
+/// keep a copy of it.
+#[derive(Debug, Clone)]
+enum AdvancedNode {
+    Array(ArrayNode),
+    Tuple(TupleNode),
+    Object(ObjectNode),
+    Optional(Node),
+    AnyOf(Vec<Node>, Vec<SchemaType>),
+    AllOf(Vec<Node>),
+    OneOf(OneOfNode),
+    Not(Node, SchemaType),
+    Tagged(TaggedNode),
+    Record(RecordNode),
+    Ref(RefNode),
+    If(IfNode),
+}
+
+#[derive(Debug, Clone)]
+struct ArrayNode {
+    items: Node,
+    require_filled: bool,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    unique_items: bool,
+    unique_by: Option<String>,
+    contains: Option<Node>,
+    min_contains: usize,
+    max_contains: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct TupleNode {
+    items: Vec<Node>,
+    rest: Option<Node>,
+    min: usize,
+    max: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct ObjectNode {
+    /// The keys in the order of the original map, so the first error is the same.
+    keys: Vec<ObjectKey>,
+    /// The same keys, to look them up when checking for additional keys.
+    object_keys: HashSet<String>,
+    additional_properties: AdditionalNode,
+    dependent_required: Vec<(String, Vec<String>)>,
+    dependent_schemas: Vec<(String, Node)>,
+    constraints: Vec<ObjectConstraint>,
+}
+
+#[derive(Debug, Clone)]
+struct ObjectKey {
+    key: String,
+    node: Node,
+    optional: bool,
+}
+
+#[derive(Debug, Clone)]
+enum AdditionalNode {
+    Allow,
+    Reject,
+    Schema(Box<Node>),
+
+    /// The policy of strict objects that don't configure one themselves, which allows the keys
+    /// that are shared with other schemas.
+    RejectUnshared,
+}
+
+#[derive(Debug, Clone)]
+struct OneOfNode {
+    variants: Vec<Node>,
+    schemas: Vec<SchemaType>,
+}
+
+#[derive(Debug, Clone)]
+struct TaggedNode {
+    discriminator: String,
+    variants: HashMap<String, Node>,
+    /// The sorted tags, which are listed when the tag is unknown.
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct RecordNode {
+    values: Node,
+    keys: Option<RecordKeyType>,
+    min_properties: Option<usize>,
+    max_properties: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct RefNode {
+    name: String,
+    /// The index of the definition, if it exists.
+    index: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct IfNode {
+    condition: Node,
+    then: Option<Node>,
+    otherwise: Option<Node>,
+    /// The description of the condition, which is included in the errors.
+    description: String,
+}
+
+impl Node {
+    fn compile(schema_type: &SchemaType, compiler: &Compiler) -> Node {
+        let compile = |schema_type: &SchemaType| Node::compile(schema_type, compiler);
+
+        let (advanced_type, error_message) = match schema_type {
+            SchemaType::Basic(_) => return Node::Leaf(schema_type.clone()),
+            SchemaType::Field(field) => {
+                return Node::Field(Box::new(compile(field.field_type())), field.error_message().cloned());
+            }
+            SchemaType::Array(item) => {
+                let array_type = ArrayType {
+                    require_filled: false,
+                    ..ArrayType::from(*item.0.clone())
+                };
+
+                return Node::advanced(AdvancedNode::Array(ArrayNode::compile(&array_type, compiler)), None);
+            }
+            SchemaType::Tuple(items) => {
+                return Node::advanced(AdvancedNode::Tuple(TupleNode::compile(items, None, compiler)), None);
+            }
+            SchemaType::Object(map) => {
+                return Node::advanced(AdvancedNode::Object(ObjectNode::compile(&ObjectType::from(map.clone()), compiler)), None);
+            }
+            SchemaType::Advanced(advanced_type) => (advanced_type, None),
+            SchemaType::WithErrorMessage(WithErrorMessage { kind, error_message }) => (kind, Some(error_message.clone())),
+        };
+
+        let node = match advanced_type {
+            AdvancedType::String(_)
+            | AdvancedType::Number(_)
+            | AdvancedType::Const(_)
+            | AdvancedType::Enum(_)
+            | AdvancedType::Custom(_) => return Node::Leaf(schema_type.clone()),
+            AdvancedType::Array(array_type) => {
+                AdvancedNode::Array(ArrayNode::compile(array_type, compiler))
+            }
+            AdvancedType::Tuple(tuple_type) => {
+                AdvancedNode::Tuple(TupleNode::compile(&tuple_type.items, tuple_type.rest.as_deref(), compiler))
+            }
+            AdvancedType::Object(object_type) => AdvancedNode::Object(ObjectNode::compile(object_type, compiler)),
+            AdvancedType::Optional(optional_type) => AdvancedNode::Optional(compile(&optional_type.kind)),
+            AdvancedType::AnyOf(any_of_type) => {
+                AdvancedNode::AnyOf(any_of_type.variants.iter().map(compile).collect(), any_of_type.variants.to_vec())
+            }
+            AdvancedType::AllOf(all_of_type) => AdvancedNode::AllOf(all_of_type.variants.iter().map(compile).collect()),
+            AdvancedType::OneOf(one_of_type) => AdvancedNode::OneOf(OneOfNode {
+                variants: one_of_type.variants.iter().map(compile).collect(),
+                schemas: one_of_type.variants.to_vec(),
+            }),
+            AdvancedType::Not(not_type) => AdvancedNode::Not(compile(&not_type.kind), (*not_type.kind).clone()),
+            AdvancedType::Tagged(tagged_type) => {
+                let mut tags = tagged_type.variants.keys()
+                    .cloned()
+                    .collect::<Vec<String>>();
+
+                tags.sort();
+
+                AdvancedNode::Tagged(TaggedNode {
+                    discriminator: tagged_type.discriminator.to_string(),
+                    variants: tagged_type.variants.iter()
+                        .map(|(tag, variant)| (tag.to_string(), compile(variant)))
+                        .collect(),
+                    tags,
+                })
+            }
+            AdvancedType::Record(record_type) => AdvancedNode::Record(RecordNode {
+                values: compile(&record_type.values),
+                keys: record_type.keys.clone(),
+                min_properties: record_type.min_properties,
+                max_properties: record_type.max_properties,
+            }),
+            AdvancedType::Ref(ref_type) => AdvancedNode::Ref(RefNode {
+                name: ref_type.name.to_string(),
+                index: compiler.names.get(ref_type.name.as_str()).copied(),
+            }),
+            AdvancedType::If(if_type) => AdvancedNode::If(IfNode {
+                condition: compile(&if_type.condition),
+                then: if_type.then.as_deref().map(compile),
+                otherwise: if_type.otherwise.as_deref().map(compile),
+                description: if_type.describe_condition(),
+            }),
+        };
+
+        Node::advanced(node, error_message)
+    }
+
+    fn advanced(node: AdvancedNode, error_message: Option<ErrorMessage>) -> Node {
+        Node::Advanced(Box::new(node), error_message)
+    }
+
+    /// Like [SchemaType::declares_key], for the compiled node.
+    fn declares_key(&self, key: &str, target_map: &Map<String, Value>, scope: Scope) -> bool {
+        match self {
+            Node::Leaf(_) => false,
+            Node::Field(node, _) => node.declares_key(key, target_map, scope),
+            Node::Advanced(node, _) => node.declares_key(key, target_map, scope),
+        }
+    }
+}
+
+impl AdvancedNode {
+    fn declares_key(&self, key: &str, target_map: &Map<String, Value>, scope: Scope) -> bool {
+        match self {
+            AdvancedNode::Object(object_node) => object_node.is_known_key(key, target_map, scope),
+            AdvancedNode::Optional(node) => node.declares_key(key, target_map, scope),
+            AdvancedNode::AnyOf(variants, _)
+            | AdvancedNode::AllOf(variants)
+            | AdvancedNode::OneOf(OneOfNode { variants, .. }) => variants.iter()
+                .any(|variant| variant.declares_key(key, target_map, scope)),
+            AdvancedNode::Tagged(tagged_node) => {
+                key == tagged_node.discriminator || target_map.get(&tagged_node.discriminator)
+                    .and_then(Value::as_str)
+                    .and_then(|tag| tagged_node.variants.get(tag))
+                    .is_some_and(|variant| variant.declares_key(key, target_map, scope))
+            }
+            AdvancedNode::Record(record_node) => record_node.keys.as_ref()
+                .is_none_or(|keys| keys.validate_key(key).is_ok()),
+            AdvancedNode::Ref(ref_node) => scope.resolve(ref_node)
+                .is_ok_and(|(definition, scope)| definition.declares_key(key, target_map, scope)),
+            AdvancedNode::If(if_node) => if_node.declares_key(key, target_map, scope),
+            AdvancedNode::Array(_)
+            | AdvancedNode::Tuple(_)
+            | AdvancedNode::Not(_, _) => false,
+        }
+    }
+}
+
+impl NodeValidator for Node {
+    type E = SchemaTypeValidationError;
+
+    fn validate_node(&self, value: &Value, scope: Scope) -> Result<(), Self::E> {
+        let (result, error_message) = match self {
+            Node::Leaf(schema_type) => return schema_type.validate(value),
+            Node::Field(node, error_message) => (node.validate_node(value, scope), error_message),
+            Node::Advanced(node, error_message) => (node.validate_node(value, scope).map_err(Into::into), error_message),
+        };
+
+        match error_message {
+            Some(error_message) => result.map_err(|error| error_message.apply(error)),
+            None => result,
+        }
+    }
+}
+
+// The validation of every node is kept in a separate function, as the size of the stack frames
+// limits how deeply nested the values can be that are validated in debug builds.
+impl NodeValidator for AdvancedNode {
+    type E = AdvancedTypeValidationError;
+
+    fn validate_node(&self, value: &Value, scope: Scope) -> Result<(), Self::E> {
+        match self {
+            AdvancedNode::Array(array_node) => array_node.validate_node(value, scope)?,
+            AdvancedNode::Tuple(tuple_node) => tuple_node.validate_node(value, scope)?,
+            AdvancedNode::Object(object_node) => object_node.validate_node(value, scope)?,
+            AdvancedNode::Optional(node) => {
+                if !value.is_null() {
+                    node.validate_node(value, scope)?;
+                }
+            }
+            AdvancedNode::AnyOf(variants, schemas) => {
+                if !variants.iter().any(|variant| variant.validate_node(value, scope).is_ok()) {
+                    return Err(AnyOfTypeError(schemas.to_vec()).into());
+                }
+            }
+            AdvancedNode::AllOf(variants) => scope.share(SharedNodes::Declared(variants), |scope| {
+                for (i, variant) in variants.iter().enumerate() {
+                    variant.validate_node(value, scope)
+                        .map_err(|error| AllOfTypeError(i, Box::new(error)))?;
+                }
+
+                Ok::<(), AllOfTypeError>(())
+            })?,
+            AdvancedNode::OneOf(one_of_node) => one_of_node.validate_node(value, scope)?,
+            AdvancedNode::Not(node, schema) => {
+                if node.validate_node(value, scope).is_ok() {
+                    return Err(NotTypeError(schema.clone()).into());
+                }
+            }
+            AdvancedNode::Tagged(tagged_node) => tagged_node.validate_node(value, scope)?,
+            AdvancedNode::Record(record_node) => record_node.validate_node(value, scope)?,
+            AdvancedNode::Ref(ref_node) => ref_node.validate_node(value, scope)?,
+            AdvancedNode::If(if_node) => if_node.validate_node(value, scope)?,
+        }
+
+        Ok(())
+    }
+}
+
+impl NodeValidator for OneOfNode {
+    type E = OneOfTypeError;
+
+    fn validate_node(&self, value: &Value, scope: Scope) -> Result<(), Self::E> {
+        let mut first_match = None;
+        let mut matches = vec![];
+
+        for (i, variant) in self.variants.iter().enumerate() {
+            if variant.validate_node(value, scope).is_err() {
+                continue;
+            }
+
+            // The matching variants are only collected once there is more than one.
+            match first_match {
+                None => first_match = Some(i),
+                Some(first) if matches.is_empty() => matches.extend([first, i]),
+                Some(_) => matches.push(i),
+            }
+        }
+
+        if first_match.is_none() {
+            return Err(OneOfTypeError::NoMatch(self.schemas.to_vec()));
+        }
+
+        if !matches.is_empty() {
+            return Err(OneOfTypeError::MultipleMatches(matches));
+        }
+
+        Ok(())
+    }
+}
+
+impl NodeValidator for RefNode {
+    type E = RefTypeError;
+
+    fn validate_node(&self, value: &Value, scope: Scope) -> Result<(), Self::E> {
+        let (definition, scope) = scope.resolve(self)?;
+
+        definition.validate_node(value, scope)
+            .map_err(RefTypeError::from)
+    }
+}
+
+impl TupleNode {
+    fn compile(items: &[SchemaType], rest: Option<&SchemaType>, compiler: &Compiler) -> TupleNode {
+        let (min, max) = TupleType::length_range(items, rest.is_some());
+
+        TupleNode {
+            items: items.iter()
+                .map(|item| Node::compile(item, compiler))
+                .collect(),
+            rest: rest.map(|rest| Node::compile(rest, compiler)),
+            min,
+            max,
+        }
+    }
+}
+
+impl NodeValidator for TupleNode {
+    type E = TupleError;
+
+    fn validate_node(&self, value: &Value, scope: Scope) -> Result<(), Self::E> {
+        let Value::Array(value_items) = value else {
+            return Err(TupleError::NotAnArray);
+        };
+
+        TupleType::check_length(self.min, self.max, value_items.len())?;
+
+        for (node, item_value) in self.items.iter().zip(value_items) {
+            node.validate_node(item_value, scope.nested())?;
+        }
+
+        if let Some(rest) = &self.rest {
+            for item_value in value_items.iter().skip(self.items.len()) {
+                rest.validate_node(item_value, scope.nested())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl NodeValidator for TaggedNode {
+    type E = TaggedTypeError;
+
+    fn validate_node(&self, value: &Value, scope: Scope) -> Result<(), Self::E> {
+        let Value::Object(target_map) = value else {
+            return Err(TaggedTypeError::NotAnObject);
+        };
+
+        let Some(tag) = target_map.get(&self.discriminator) else {
+            return Err(TaggedTypeError::MissingDiscriminator(self.discriminator.to_string()));
+        };
+
+        let Some(variant) = tag.as_str().and_then(|tag| self.variants.get(tag)) else {
+            return Err(TaggedTypeError::UnknownTag(tag.clone(), self.tags.to_vec()));
+        };
+
+        scope.share(SharedNodes::Key(&self.discriminator), |scope| variant.validate_node(value, scope).map_err(TaggedTypeError::from))?;
+        Ok(())
+    }
+}
+
+impl NodeValidator for RecordNode {
+    type E = RecordTypeError;
+
+    fn validate_node(&self, value: &Value, scope: Scope) -> Result<(), Self::E> {
+        let Value::Object(target_map) = value else {
+            return Err(RecordTypeError::NotAnObject);
+        };
+
+        RecordType::validate_size(self.min_properties, self.max_properties, target_map.len())?;
+
+        for (key, value) in target_map {
+            if let Some(keys) = &self.keys {
+                keys.validate_key(key)
+                    .map_err(|error| RecordTypeError::InvalidKey(key.to_string(), error))?;
+            }
+
+            self.values.validate_node(value, scope.nested())
+                .map_err(|error| RecordTypeError::InvalidValue(key.to_string(), Box::new(error)))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl NodeValidator for IfNode {
+    type E = IfTypeError;
+
+    fn validate_node(&self, value: &Value, scope: Scope) -> Result<(), Self::E> {
+        let matched = scope.share(SharedNodes::If(self), |scope| self.condition.validate_node(value, scope).is_ok());
+        let branch = if matched { &self.then } else { &self.otherwise };
+
+        let Some(branch) = branch else {
+            return Ok(());
+        };
+
+        scope.share(SharedNodes::If(self), |scope| {
+            branch.validate_node(value, scope).map_err(|error| match matched {
+                true => IfTypeError::ThenError(self.description.to_string(), Box::new(error)),
+                false => IfTypeError::ElseError(self.description.to_string(), Box::new(error)),
+            })
+        })
+    }
+}
+
+impl IfNode {
+    fn declares_key(&self, key: &str, target_map: &Map<String, Value>, scope: Scope) -> bool {
+        std::iter::once(&self.condition)
+            .chain(&self.then)
+            .chain(&self.otherwise)
+            .any(|node| node.declares_key(key, target_map, scope))
+    }
+}
+
+impl ArrayNode {
+    fn compile(array_type: &ArrayType, compiler: &Compiler) -> ArrayNode {
+        ArrayNode {
+            items: Node::compile(&array_type.items, compiler),
+            require_filled: array_type.require_filled,
+            min_items: array_type.min_items,
+            max_items: array_type.max_items,
+            unique_items: array_type.unique_items,
+            unique_by: array_type.unique_by.clone(),
+            contains: array_type.contains.as_deref()
+                .map(|contains| Node::compile(contains, compiler)),
+            min_contains: array_type.min_contains.unwrap_or(1),
+            max_contains: array_type.max_contains,
+        }
+    }
+}
+
+impl NodeValidator for ArrayNode {
+    type E = ArrayTypeError;
+
+    fn validate_node(&self, value: &Value, scope: Scope) -> Result<(), Self::E> {
+        let Value::Array(items) = value else {
+            return Err(ArrayTypeError::NotAnArray);
+        };
+
+        if items.is_empty() && self.require_filled {
+            return Err(ArrayTypeError::RequireFilled);
+        }
+
+        for item in items {
+            self.items.validate_node(item, scope.nested())?;
+        }
+
+        let contains = self.contains.as_ref()
+            .map(|contains| |item: &Value| contains.validate_node(item, scope.nested()).is_ok());
+
+        let constraints = ArrayConstraints {
+            min_items: self.min_items,
+            max_items: self.max_items,
+            unique_items: self.unique_items,
+            unique_by: self.unique_by.as_deref(),
+            min_contains: self.min_contains,
+            max_contains: self.max_contains,
+        };
+
+        if let ControlFlow::Break(error) = constraints.check(items, contains, |_, error| ControlFlow::Break(error)) {
+            return Err(error);
+        }
+
+        Ok(())
+    }
+}
+
+impl ObjectNode {
+    fn compile(object_type: &ObjectType, compiler: &Compiler) -> ObjectNode {
+        let mut dependent_required = object_type.dependent_required.iter()
+            .map(|(trigger, required)| (trigger.to_string(), required.to_vec()))
+            .collect::<Vec<(String, Vec<String>)>>();
+
+        dependent_required.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+        let mut dependent_schemas = object_type.dependent_schemas.iter()
+            .map(|(trigger, schema)| (trigger.to_string(), Node::compile(schema, compiler)))
+            .collect::<Vec<(String, Node)>>();
+
+        dependent_schemas.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+        ObjectNode {
+            keys: object_type.object.iter()
+                .map(|(key, schema)| ObjectKey {
+                    key: key.to_string(),
+                    node: Node::compile(schema, compiler),
+                    optional: matches!(schema.as_advanced(), Some(AdvancedType::Optional(_))),
+                })
+                .collect(),
+            object_keys: object_type.object.keys().cloned().collect(),
+            additional_properties: match &object_type.additional_properties {
+                Some(AdditionalProperties::Allow) => AdditionalNode::Allow,
+                Some(AdditionalProperties::Reject) => AdditionalNode::Reject,
+                Some(AdditionalProperties::Schema(schema)) => AdditionalNode::Schema(Box::new(Node::compile(schema, compiler))),
+                None if compiler.strict_objects => AdditionalNode::RejectUnshared,
+                None => AdditionalNode::Allow,
+            },
+            dependent_required,
+            dependent_schemas,
+            constraints: object_type.constraints.to_vec(),
+        }
+    }
+}
+
+impl ObjectNode {
+    /// Like [ObjectType::is_known_key], for the compiled node.
+    fn is_known_key(&self, key: &str, target_map: &Map<String, Value>, scope: Scope) -> bool {
+        if self.object_keys.contains(key) {
+            return true;
+        }
+
+        let required = self.dependent_required.iter()
+            .filter(|(trigger, _)| target_map.contains_key(trigger))
+            .any(|(trigger, required)| trigger == key || required.iter().any(|required| required == key));
+
+        required || self.dependent_schemas.iter()
+            .filter(|(trigger, _)| target_map.contains_key(trigger))
+            .any(|(trigger, node)| trigger == key || node.declares_key(key, target_map, scope))
+    }
+}
+
+impl NodeValidator for ObjectNode {
+    type E = ObjectTypeError;
+
+    fn validate_node(&self, value: &Value, scope: Scope) -> Result<(), Self::E> {
+        let Value::Object(target_map) = value else {
+            return Err(ObjectTypeError::NotAnObject);
+        };
+
+        for ObjectKey { key, node, optional } in &self.keys {
+            let Some(value) = target_map.get(key) else {
+                if *optional {
+                    continue;
+                }
+
+                return Err(ObjectTypeError::MissingObjectKey(key.to_string()));
+            };
+
+            node.validate_node(value, scope.nested())?;
+        }
+
+        let dependent_required = self.dependent_required.iter()
+            .map(|(trigger, required)| (trigger, required));
+
+        if let Some((_, error)) = ObjectType::missing_dependent_keys(dependent_required, target_map).next() {
+            return Err(error);
+        }
+
+        for (trigger, node) in &self.dependent_schemas {
+            if !target_map.contains_key(trigger) {
+                continue;
+            }
+
+            scope.share(SharedNodes::Object(self), |scope| {
+                node.validate_node(value, scope)
+                    .map_err(|error| ObjectTypeError::DependentSchemaError(trigger.to_string(), Box::new(error)))
+            })?;
+        }
+
+        for constraint in &self.constraints {
+            constraint.validate(value)?;
+        }
+
+        if let AdditionalNode::Allow = self.additional_properties {
+            return Ok(());
+        }
+
+        for (key, value) in target_map {
+            if self.is_known_key(key, target_map, scope) {
+                continue;
+            }
+
+            match &self.additional_properties {
+                AdditionalNode::Allow => {}
+                AdditionalNode::Reject => return Err(ObjectTypeError::UnexpectedKey(key.to_string())),
+                AdditionalNode::Schema(node) => node.validate_node(value, scope.nested())?,
+                AdditionalNode::RejectUnshared => {
+                    if !scope.is_shared(key, target_map) {
+                        return Err(ObjectTypeError::UnexpectedKey(key.to_string()));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use crate::compiled_schema::CompiledSchema;
+    use crate::schema::definitions::{Definitions, MAX_REF_DEPTH};
+    use crate::schema::Schema;
+    use crate::schema::validation_scope::ValidationScope;
+    use crate::schema_type::SchemaType;
+    use crate::traits::validator::Validator;
+
+    /// Values of every kind, which are validated against every schema in the differential tests.
+    fn sample_values() -> Vec<Value> {
+        vec![
+            json!(null),
+            json!(true),
+            json!(0),
+            json!(-3),
+            json!(12.5),
+            json!(300),
+            json!(""),
+            json!("Alice"),
+            json!("2024-02-30"),
+            json!("12"),
+            json!([]),
+            json!(["a", "b"]),
+            json!(["a", "a", 1]),
+            json!([1, 2, 3, 4]),
+            json!([{ "sku": "A" }, { "sku": "B" }, { "sku": "A" }]),
+            json!({}),
+            json!({ "name": "Alice", "age": 30 }),
+            json!({ "name": "", "age": 300, "extra": true }),
+            json!({ "type": "circle", "radius": 2 }),
+            json!({ "type": "square", "size": "big" }),
+            json!({ "type": 1 }),
+            json!({ "country": "US", "state": "CA" }),
+            json!({ "country": "US" }),
+            json!({ "min": 5, "max": 2, "phone": "123", "email": "a@b.c" }),
+            json!({ "password": "secret", "confirm": "secrte" }),
+            json!({ "name": "Alice", "address": { "street": "Main", "number": 1 } }),
+        ]
+    }
+
+    /// Compares the results with and without strict objects, see [Schema::set_strict_objects].
+    fn assert_same_results(schema_type: &SchemaType, definitions: &Definitions, extra_values: &[Value]) {
+        for strict_objects in [false, true] {
+            let compiled = CompiledSchema::new(schema_type, definitions, strict_objects);
+
+            for value in sample_values().iter().chain(extra_values) {
+                let scope = ValidationScope::new(definitions).with_strict_objects(strict_objects);
+
+                assert_eq!(
+                    compiled.validate(value),
+                    schema_type.validate_in(value, scope),
+                    "schema {} with value {} and strict objects {}",
+                    schema_type,
+                    value,
+                    strict_objects
+                );
+            }
+        }
+    }
+
+    fn assert_same_results_for(schema: Value, extra_values: &[Value]) {
+        let schema_type = serde_json::from_value::<SchemaType>(schema).unwrap();
+
+        assert_same_results(&schema_type, &Definitions::default(), extra_values);
+    }
+
+    #[test]
+    fn basic_and_leaf_types_give_the_same_results() {
+        let schemas = [
+            json!("string"),
+            json!("filledString"),
+            json!("u8"),
+            json!("date"),
+            json!("any"),
+            json!({ "$": "string", "minLength": 2, "pattern": "^[A-Z]" }),
+            json!({ "$": "number", "minimum": 0, "multipleOf": 3 }),
+            json!({ "$": "const", "value": "Alice" }),
+            json!({ "$": "enum", "values": [0, "Alice", null] }),
+            json!({ "$": "string", "maxLength": 3, "errorMessage": "Too long" }),
+        ];
+
+        for schema in schemas {
+            assert_same_results_for(schema, &[]);
+        }
+    }
+
+    #[test]
+    fn shorthands_give_the_same_results() {
+        let schemas = [
+            json!(["string"]),
+            json!([["u8"]]),
+            json!(["string", "u8"]),
+            json!(["string", { "$": "optional", "type": "u8" }]),
+            json!({ "name": "filledString", "age": "u8" }),
+            json!({ "name": "string", "age": { "$": "optional", "type": "u8" }, "tags": ["string"] }),
+            json!({ "type": "string", "radius": { "$": "optional", "type": "number" } }),
+            json!({ "name": "string", "address": { "street": "string" } }),
+        ];
+
+        for schema in schemas {
+            assert_same_results_for(schema, &[json!({ "name": "Bob", "age": 1, "tags": ["a", 2] })]);
+        }
+    }
+
+    #[test]
+    fn containers_give_the_same_results() {
+        let schemas = [
+            json!({ "$": "array", "items": "any", "minItems": 2, "maxItems": 3, "uniqueItems": true }),
+            json!({ "$": "array", "items": { "sku": "string" }, "uniqueBy": "/sku" }),
+            json!({ "$": "array", "items": "any", "requireFilled": false, "contains": "number", "minContains": 2, "maxContains": 3 }),
+            json!({ "$": "tuple", "items": ["string", { "$": "optional", "type": "string" }], "rest": "number" }),
+            json!({
+                "$": "object",
+                "object": { "name": "string", "age": { "$": "optional", "type": "u8" } },
+                "additionalProperties": false,
+            }),
+            json!({
+                "$": "object",
+                "object": { "name": "string" },
+                "additionalProperties": "number",
+            }),
+            json!({
+                "$": "object",
+                "object": {},
+                "dependentRequired": { "country": ["state", "zip"], "min": ["max"] },
+                "dependentSchemas": { "country": { "state": { "$": "enum", "values": ["CA", "NY"] } } },
+            }),
+            json!({
+                "$": "object",
+                "object": {},
+                "constraints": [
+                    { "$": "lessThan", "left": "/min", "right": "/max" },
+                    { "$": "equal", "left": "/confirm", "right": "/password" },
+                    { "$": "mutuallyExclusive", "paths": ["/phone", "/email"] },
+                ],
+            }),
+            json!({ "$": "record", "values": "number", "keys": { "$": "string", "maxLength": 3 }, "maxProperties": 2 }),
+            json!({ "$": "record", "values": "any", "keys": "^[a-z]+$", "minProperties": 1 }),
+        ];
+
+        for schema in schemas {
+            assert_same_results_for(schema, &[json!([[1], [1.0]]), json!({ "min": 1, "max": 2, "abc": 1 })]);
+        }
+    }
+
+    #[test]
+    fn combinators_give_the_same_results() {
+        let schemas = [
+            json!({ "$": "optional", "type": "filledString" }),
+            json!({ "$": "anyOf", "variants": ["u8", "filledString", { "name": "string" }] }),
+            json!({ "$": "allOf", "variants": [{ "name": "string" }, { "age": "u8" }] }),
+            json!({
+                "$": "allOf",
+                "variants": [
+                    { "name": "string", "address": { "$": "optional", "type": { "street": "string" } } },
+                    { "$": "object", "object": { "age": "u8" }, "additionalProperties": false },
+                ],
+            }),
+            json!({
+                "$": "allOf",
+                "variants": [
+                    { "name": "string" },
+                    { "$": "record", "values": "any", "keys": "^a" },
+                    { "$": "tagged", "discriminator": "type", "variants": { "circle": { "radius": "number" } } },
+                ],
+            }),
+            json!({ "$": "oneOf", "variants": ["number", "u8", "i8", "string"] }),
+            json!({ "$": "not", "type": ["string"] }),
+            json!({
+                "$": "tagged",
+                "discriminator": "type",
+                "variants": { "circle": { "radius": "number" }, "square": { "size": "number" } },
+            }),
+            json!({
+                "$": "if",
+                "if": { "country": { "$": "const", "value": "US" } },
+                "then": { "state": "string" },
+                "else": { "$": "object", "object": {}, "additionalProperties": false },
+            }),
+            json!({
+                "?": { "name": "filledString", "age": "u8" },
+                "label": "Person",
+                "hint": null,
+                "errorMessage": { "object.missingKey": "Please fill in all fields" },
+            }),
+            json!({
+                "$": "allOf",
+                "variants": [{ "$": "string", "errorMessage": "Inner" }],
+                "errorMessage": "Outer",
+            }),
+        ];
+
+        for schema in schemas {
+            assert_same_results_for(schema, &[]);
+        }
+    }
+
+    #[test]
+    fn references_give_the_same_results() {
+        let schema = serde_json::from_value::<Schema>(json!({
+            "version": 0,
+            "initial": [{ "$": "ref", "name": "comment" }],
+            "changes": [],
+            "definitions": {
+                "comment": {
+                    "text": "filledString",
+                    "replies": [{ "$": "ref", "name": "comment" }],
+                },
+            },
+        }))
+            .unwrap();
+
+        let mut deep = json!({ "text": "Deepest", "replies": [] });
+        for _ in 0..MAX_REF_DEPTH {
+            deep = json!({ "text": "Reply", "replies": [deep] });
+        }
+
+        let values = [
+            json!([{ "text": "Hi", "replies": [{ "text": "Hello", "replies": [] }] }]),
+            json!([{ "text": "Hi", "replies": [{ "text": "", "replies": [] }] }]),
+            json!([deep]),
+        ];
+
+        assert!(schema.compile().validate(&values[2]).is_err());
+        assert_same_results(schema.current(), schema.definitions(), &values);
+
+        for value in &values {
+            assert_eq!(schema.compile().validate(value), schema.validate(value));
+        }
+
+        let unknown = serde_json::from_value::<SchemaType>(json!({ "$": "ref", "name": "missing" })).unwrap();
+        assert_same_results(&unknown, &Definitions::default(), &[]);
+    }
+}
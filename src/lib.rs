@@ -2,5 +2,8 @@ pub mod schema;
 pub mod schema_type;
 pub mod traits;
 pub mod migration;
+pub mod report;
+pub mod messages;
+pub mod compiled_schema;
 
 mod shared;
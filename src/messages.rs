@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use crate::traits::error_code::ErrorCode;
+
+/// The locale of the bundled English catalogue, which is used when no other catalogue has a
+/// message for an error.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Message templates for a single locale, keyed by error code, see [ErrorCode]. Templates refer to
+/// the params of an error by name, like `Expected at most {max} items, but got {length}`. The
+/// catalogue (de)serializes as an object that maps each code to its template, so the bundled
+/// English catalogue in `src/messages/en.json` can be used as a starting point for other locales.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MessageCatalogue(HashMap<String, String>);
+
+impl MessageCatalogue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The bundled English messages, which cover every error code of this crate.
+    pub fn english() -> Self {
+        serde_json::from_str(include_str!("messages/en.json"))
+            .expect("The bundled English messages should be valid")
+    }
+
+    pub fn get(&self, code: &str) -> Option<&str> {
+        self.0.get(code)
+            .map(|template| template.as_str())
+    }
+
+    pub fn insert(&mut self, code: impl Into<String>, template: impl Into<String>) {
+        self.0.insert(code.into(), template.into());
+    }
+}
+
+impl<const U: usize> From<[(&str, &str); U]> for MessageCatalogue {
+    fn from(value: [(&str, &str); U]) -> Self {
+        MessageCatalogue(
+            value.into_iter()
+                .map(|(code, template)| (code.to_string(), template.to_string()))
+                .collect()
+        )
+    }
+}
+
+/// Renders errors in a chosen locale using a [MessageCatalogue] per locale. English is included
+/// by default. For a locale like `nl-BE`, the catalogue for `nl-BE` is tried first, then the one
+/// for `nl` and finally the English catalogue. Errors without a message in any of those render as
+/// their code.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Messages {
+    catalogues: HashMap<String, MessageCatalogue>,
+}
+
+impl Default for Messages {
+    fn default() -> Self {
+        Messages {
+            catalogues: HashMap::from([(DEFAULT_LOCALE.to_string(), MessageCatalogue::english())]),
+        }
+    }
+}
+
+impl Messages {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the catalogue for the given locale. Messages that are already known for the locale are
+    /// replaced, so this can also be used to override some of the bundled English messages.
+    pub fn add_catalogue(&mut self, locale: impl Into<String>, catalogue: MessageCatalogue) {
+        let existing = self.catalogues.entry(locale.into()).or_default();
+
+        for (code, template) in catalogue.0 {
+            existing.insert(code, template);
+        }
+    }
+
+    /// Renders the error in the given locale. Messages that were set in the schema are used as
+    /// they are, see [ErrorMessage].
+    ///
+    /// [ErrorMessage]: crate::schema_type::error_message::ErrorMessage
+    pub fn render(&self, error: &impl ErrorCode, locale: &str) -> String {
+        if let Some(message) = error.custom_message() {
+            return message.to_string();
+        }
+
+        self.render_code(error.code(), &error.params(), locale)
+    }
+
+    fn render_code(&self, code: &str, params: &Map<String, Value>, locale: &str) -> String {
+        let Some(template) = self.template(code, locale) else {
+            return code.to_string();
+        };
+
+        interpolate(template, |name| {
+            params.get(name)
+                .map(|param| self.render_param(param, locale))
+        })
+    }
+
+    fn template(&self, code: &str, locale: &str) -> Option<&str> {
+        let language = locale.split(['-', '_']).next()
+            .unwrap_or(locale);
+
+        [locale, language, DEFAULT_LOCALE].into_iter()
+            .filter_map(|locale| self.catalogues.get(locale))
+            .find_map(|catalogue| catalogue.get(code))
+    }
+
+    /// Renders a param for use in a message. Nested errors, which serialize as `code` and `params`,
+    /// are rendered in the same locale, unless they have a message that was set in the schema.
+    fn render_param(&self, param: &Value, locale: &str) -> String {
+        match param {
+            Value::Null => String::new(),
+            Value::String(string) => string.to_string(),
+            // Formats whole floats without a fraction, like the messages of the errors themselves.
+            Value::Number(number) if number.is_f64() => number.as_f64()
+                .map(|float| float.to_string())
+                .unwrap_or_else(|| number.to_string()),
+            Value::Array(items) => items.iter()
+                .map(|item| self.render_param(item, locale))
+                .collect::<Vec<String>>()
+                .join(", "),
+            Value::Object(object) => {
+                if let Some(Value::String(message)) = object.get("message") {
+                    return message.to_string();
+                }
+
+                let (Some(Value::String(code)), Some(Value::Object(params))) = (object.get("code"), object.get("params")) else {
+                    return param.to_string();
+                };
+
+                self.render_code(code, params, locale)
+            }
+            param => param.to_string(),
+        }
+    }
+}
+
+/// Replaces every `{name}` in the template with the value returned for that name. Placeholders
+/// without a value are kept as they are.
+fn interpolate(template: &str, mut value_for: impl FnMut(&str) -> Option<String>) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let placeholder = rest[1..].find('}')
+            .map(|end| &rest[1..end + 1])
+            .filter(|name| !name.is_empty() && name.chars().all(|char| char.is_ascii_alphanumeric()));
+
+        match placeholder.and_then(|name| value_for(name).map(|value| (name, value))) {
+            Some((name, value)) => {
+                result.push_str(&value);
+                rest = &rest[name.len() + 2..];
+            }
+            None => {
+                result.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use crate::messages::{MessageCatalogue, Messages};
+    use crate::schema_type::SchemaType;
+    use crate::traits::validator::Validator;
+
+    fn parse_schema_type(value: Value) -> SchemaType {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn dutch() -> MessageCatalogue {
+        MessageCatalogue::from([
+            ("string.tooLong", "De tekst is {length} tekens lang, maar mag maximaal {max} tekens lang zijn"),
+            ("object.missingKey", "Het veld '{key}' ontbreekt"),
+            ("if.then", "Omdat {condition}: {error}"),
+        ])
+    }
+
+    #[test]
+    fn english_messages_match_the_error_messages() {
+        let messages = Messages::default();
+
+        let cases = [
+            (parse_schema_type(json!({ "$": "string", "maxLength": 3 })), json!("Alice")),
+            (parse_schema_type(json!({ "$": "array", "items": "string", "maxItems": 1 })), json!(["a", "b"])),
+            (parse_schema_type(json!({ "$": "tuple", "items": ["string", "u8"] })), json!(["a"])),
+            (parse_schema_type(json!({ "$": "tuple", "items": ["string"], "rest": "u8" })), json!([])),
+            (parse_schema_type(json!({ "$": "number", "minimum": 10 })), json!(5)),
+            (parse_schema_type(json!({ "name": "string" })), json!({})),
+            (parse_schema_type(json!("u8")), json!(300)),
+            (parse_schema_type(json!("date")), json!("yesterday")),
+        ];
+
+        for (schema_type, value) in cases {
+            let error = schema_type.validate(&value).unwrap_err();
+
+            assert_eq!(messages.render(&error, "en"), error.to_string());
+        }
+    }
+
+    #[test]
+    fn messages_are_rendered_in_the_chosen_locale() {
+        let mut messages = Messages::default();
+        messages.add_catalogue("nl", dutch());
+
+        let error = parse_schema_type(json!({ "$": "string", "maxLength": 3 }))
+            .validate(&json!("Alice"))
+            .unwrap_err();
+
+        assert_eq!(messages.render(&error, "nl"), "De tekst is 5 tekens lang, maar mag maximaal 3 tekens lang zijn");
+        assert_eq!(messages.render(&error, "nl-BE"), "De tekst is 5 tekens lang, maar mag maximaal 3 tekens lang zijn");
+        assert_eq!(messages.render(&error, "de"), "The provided string has a length of 5, but should be at most 3");
+    }
+
+    #[test]
+    fn nested_errors_are_rendered_in_the_same_locale() {
+        let mut messages = Messages::default();
+        messages.add_catalogue("nl", dutch());
+
+        let error = parse_schema_type(json!({
+            "$": "if",
+            "if": { "country": { "$": "const", "value": "US" } },
+            "then": { "state": "string" },
+        }))
+            .validate(&json!({ "country": "US" }))
+            .unwrap_err();
+
+        assert_eq!(messages.render(&error, "nl"), "Omdat 'country' is \"US\": Het veld 'state' ontbreekt");
+    }
+
+    #[test]
+    fn unknown_codes_and_params_are_kept() {
+        let mut messages = Messages::default();
+        messages.add_catalogue("en", MessageCatalogue::from([
+            ("object.missingKey", "Please fill in {key} {unknown} {}"),
+        ]));
+
+        let error = parse_schema_type(json!({ "name": "string" }))
+            .validate(&json!({}))
+            .unwrap_err();
+
+        assert_eq!(messages.render(&error, "en"), "Please fill in name {unknown} {}");
+
+        let messages = Messages {
+            catalogues: Default::default(),
+        };
+
+        assert_eq!(messages.render(&error, "en"), "object.missingKey");
+    }
+
+    #[test]
+    fn messages_set_in_the_schema_are_kept() {
+        let mut messages = Messages::default();
+        messages.add_catalogue("nl", dutch());
+
+        let error = parse_schema_type(json!({
+            "$": "if",
+            "if": { "country": { "$": "const", "value": "US" } },
+            "then": {
+                "$": "object",
+                "object": { "state": "string" },
+                "errorMessage": { "object.missingKey": "Please select your state" },
+            },
+        }))
+            .validate(&json!({ "country": "US" }))
+            .unwrap_err();
+
+        assert_eq!(messages.render(&error, "nl"), "Omdat 'country' is \"US\": Please select your state");
+
+        let error = parse_schema_type(json!({ "$": "string", "maxLength": 3, "errorMessage": "Too long" }))
+            .validate(&json!("Alice"))
+            .unwrap_err();
+
+        assert_eq!(messages.render(&error, "nl"), "Too long");
+    }
+}
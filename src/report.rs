@@ -0,0 +1,63 @@
+pub mod validation_context;
+pub mod validation_path;
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use crate::report::validation_path::ValidationPath;
+use crate::schema_type::SchemaTypeValidationError;
+use crate::traits::error_code::ErrorCode;
+
+/// A single failure that was found while walking a document in collect-all mode.
+#[derive(Debug, PartialEq)]
+pub struct ReportedError {
+    /// Location of the rejected value inside the validated document.
+    pub instance_path: ValidationPath,
+
+    /// Location of the schema node that rejected the value.
+    pub schema_path: ValidationPath,
+
+    pub error: SchemaTypeValidationError,
+}
+
+/// Serializes as `{ "code": ..., "path": ..., "params": { ... } }`, where the path is the instance
+/// path formatted as a JSON Pointer. See [ErrorCode] for the code, params and optional message.
+impl Serialize for ReportedError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ReportedError", 4)?;
+        state.serialize_field("code", self.error.code())?;
+        state.serialize_field("path", &self.instance_path.to_json_pointer())?;
+        state.serialize_field("params", &self.error.params())?;
+
+        match self.error.custom_message() {
+            Some(message) => state.serialize_field("message", message)?,
+            None => state.skip_field("message")?,
+        }
+
+        state.end()
+    }
+}
+
+/// Result of validating a complete document using
+/// [crate::traits::reporting_validator::ReportingValidator]. Unlike
+/// [crate::traits::validator::Validator::validate], this does not stop at the first failure, but
+/// lists every error that was found in the document.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct ValidationReport {
+    pub errors: Vec<ReportedError>,
+}
+
+impl ValidationReport {
+    /// Returns true if no errors were found.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Converts the report into a result, which is `Ok` if no errors were found.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_valid() {
+            return Ok(());
+        }
+
+        Err(self)
+    }
+}
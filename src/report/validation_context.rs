@@ -0,0 +1,209 @@
+use crate::report::{ReportedError, ValidationReport};
+use crate::report::validation_path::{PathSegment, ValidationPath};
+use crate::schema::definitions::Definitions;
+use crate::schema::validation_scope::ValidationScope;
+use crate::schema_type::advanced_type::object_type::SharedKeys;
+use crate::schema_type::advanced_type::ref_type::RefTypeError;
+use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use serde_json::Value;
+
+/// Keeps track of the errors that have been found while walking a document and the current
+/// location in both the document and the schema. This is passed down to every nested type when
+/// using [crate::traits::reporting_validator::ReportingValidator]. It also holds the state of the
+/// [ValidationScope] that the document is validated in.
+#[derive(Debug, Default)]
+pub struct ValidationContext {
+    instance_path: ValidationPath,
+    schema_path: ValidationPath,
+    errors: Vec<ReportedError>,
+    definitions: Definitions,
+    depth: usize,
+    strict_objects: bool,
+    /// The keys that are shared for the value that is currently being validated, see
+    /// [ValidationContext::share].
+    shared_keys: Vec<String>,
+    /// Whether only the first error is recorded, see [ValidationContext::first_error].
+    first_error: bool,
+}
+
+impl ValidationContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a context that only records the first error that is reported, which is used to
+    /// find the location of the error when validating fail-fast.
+    pub fn first_error() -> Self {
+        ValidationContext {
+            first_error: true,
+            ..Self::default()
+        }
+    }
+
+    /// Location of the value that is currently being validated.
+    pub fn instance_path(&self) -> &ValidationPath {
+        &self.instance_path
+    }
+
+    /// Location of the schema node that is currently validating.
+    pub fn schema_path(&self) -> &ValidationPath {
+        &self.schema_path
+    }
+
+    /// The scope to use when a nested schema is validated using
+    /// [crate::traits::validator::Validator::validate_in].
+    pub fn scope(&self) -> ValidationScope<'_> {
+        ValidationScope::new(&self.definitions)
+            .at_depth(self.depth)
+            .with_strict_objects(self.strict_objects)
+            .with_shared_keys(SharedKeys::Keys(&self.shared_keys))
+    }
+
+    /// Runs the provided closure in the given scope, restoring the current scope afterwards.
+    pub fn enter_scope<R>(&mut self, scope: ValidationScope, f: impl FnOnce(&mut Self) -> R) -> R {
+        let definitions = scope.definitions().cloned().unwrap_or_default();
+        let definitions = std::mem::replace(&mut self.definitions, definitions);
+        let depth = std::mem::replace(&mut self.depth, scope.depth());
+        let strict_objects = std::mem::replace(&mut self.strict_objects, scope.strict_objects());
+        let shared_keys = std::mem::take(&mut self.shared_keys);
+
+        let result = f(self);
+
+        self.definitions = definitions;
+        self.depth = depth;
+        self.strict_objects = strict_objects;
+        self.shared_keys = shared_keys;
+
+        result
+    }
+
+    /// Runs the provided closure with the given segment appended to the instance path. Used when
+    /// descending into an item of an array or a value of an object, so the keys that are shared
+    /// for the current value don't apply inside the closure.
+    pub fn enter_instance<R>(
+        &mut self,
+        segment: impl Into<PathSegment>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        self.instance_path.push(segment.into());
+        let shared_keys = std::mem::take(&mut self.shared_keys);
+        let result = f(self);
+        self.shared_keys = shared_keys;
+        self.instance_path.pop();
+
+        result
+    }
+
+    /// Runs the provided closure with the keys of the value that are in `keys` shared, like
+    /// [ValidationScope::share] does when validating fail-fast. The keys are collected up front,
+    /// as the context is passed on while the closure runs.
+    pub(crate) fn share<R>(&mut self, value: &Value, keys: SharedKeys, f: impl FnOnce(&mut Self) -> R) -> R {
+        let start = self.shared_keys.len();
+
+        if let (true, Value::Object(target_map)) = (self.strict_objects, value) {
+            let scope = self.scope();
+            let shared = target_map.keys()
+                .filter(|key| keys.contains(key, target_map, scope))
+                .cloned()
+                .collect::<Vec<String>>();
+
+            self.shared_keys.extend(shared);
+        }
+
+        let result = f(self);
+        self.shared_keys.truncate(start);
+
+        result
+    }
+
+    /// Runs the provided closure with the given segment appended to the schema path. Used when
+    /// descending into a nested schema.
+    pub fn enter_schema<R>(
+        &mut self,
+        segment: impl Into<PathSegment>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        self.schema_path.push(segment.into());
+        let result = f(self);
+        self.schema_path.pop();
+
+        result
+    }
+
+    /// Runs the provided closure with the definition with the given name from the scope, see
+    /// [ValidationScope::resolve]. While the closure runs, the schema path is set to the
+    /// definition, like `/definitions/comment`, so it points to the definition instead of to a
+    /// location that only exists once the reference is resolved.
+    pub fn enter_definition<R>(
+        &mut self,
+        name: &str,
+        f: impl FnOnce(&SchemaType, &mut Self) -> R,
+    ) -> Result<R, RefTypeError> {
+        // The definitions are cloned, so the definition is not borrowed from the context while the
+        // context is passed on.
+        let definitions = self.definitions.clone();
+        let (definition, scope) = ValidationScope::new(&definitions)
+            .at_depth(self.depth)
+            .resolve(name)?;
+
+        let definition_path = ValidationPath {
+            segments: vec!["definitions".into(), name.into()],
+        };
+
+        let schema_path = std::mem::replace(&mut self.schema_path, definition_path);
+        let depth = std::mem::replace(&mut self.depth, scope.depth());
+        let result = f(definition, self);
+        self.schema_path = schema_path;
+        self.depth = depth;
+
+        Ok(result)
+    }
+
+    /// Records an error for the value that is currently being validated.
+    pub fn report(&mut self, error: impl Into<SchemaTypeValidationError>) {
+        if self.first_error && !self.errors.is_empty() {
+            return;
+        }
+
+        self.errors.push(ReportedError {
+            instance_path: self.instance_path.clone(),
+            schema_path: self.schema_path.clone(),
+            error: error.into(),
+        });
+    }
+
+    /// Records the result of a fail-fast validation, doing nothing if the result is `Ok`.
+    pub fn report_result<E: Into<SchemaTypeValidationError>>(&mut self, result: Result<(), E>) {
+        if let Err(error) = result {
+            self.report(error);
+        }
+    }
+
+    /// Runs the provided closure and replaces every error that is reported inside it with the
+    /// result of `wrap`. Used to add the reason a nested schema has been applied to its errors.
+    pub fn wrap_errors<R>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> R,
+        wrap: impl Fn(SchemaTypeValidationError) -> SchemaTypeValidationError,
+    ) -> R {
+        let start = self.errors.len();
+        let result = f(self);
+
+        let wrapped = self.errors.drain(start..)
+            .map(|reported| ReportedError {
+                error: wrap(reported.error),
+                ..reported
+            })
+            .collect::<Vec<ReportedError>>();
+
+        self.errors.extend(wrapped);
+
+        result
+    }
+
+    pub fn into_report(self) -> ValidationReport {
+        ValidationReport {
+            errors: self.errors,
+        }
+    }
+}
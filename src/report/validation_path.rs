@@ -0,0 +1,166 @@
+use std::fmt::{Display, Formatter};
+use json_search::json_path::JsonPath;
+use serde_json::Value;
+
+/// A single step into a JSON document, either a key in an object or an index in an array.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathSegment::Key(key) => write!(f, "{}", key),
+            PathSegment::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+impl From<&str> for PathSegment {
+    fn from(value: &str) -> Self {
+        PathSegment::Key(value.to_string())
+    }
+}
+
+impl From<String> for PathSegment {
+    fn from(value: String) -> Self {
+        PathSegment::Key(value)
+    }
+}
+
+impl From<&String> for PathSegment {
+    fn from(value: &String) -> Self {
+        PathSegment::Key(value.to_string())
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(value: usize) -> Self {
+        PathSegment::Index(value)
+    }
+}
+
+/// Location inside either the validated document or the schema. Formats as a JSON Pointer
+/// ([RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901)) and can be converted into a
+/// [JsonPath] to be used together with migrations.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ValidationPath {
+    pub segments: Vec<PathSegment>,
+}
+
+impl ValidationPath {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn push(&mut self, segment: PathSegment) {
+        self.segments.push(segment);
+    }
+
+    pub(crate) fn pop(&mut self) {
+        self.segments.pop();
+    }
+
+    /// Formats the path as a JSON Pointer, for example `/users/0/name`. The root is an empty
+    /// string.
+    pub fn to_json_pointer(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| {
+                let segment = segment.to_string()
+                    .replace('~', "~0")
+                    .replace('/', "~1");
+
+                format!("/{}", segment)
+            })
+            .collect()
+    }
+
+    /// Formats the path using JSON path notation, for example `$.users[0].name`.
+    fn to_json_path_string(&self) -> String {
+        let mut path = "$".to_string();
+
+        for segment in &self.segments {
+            match segment {
+                PathSegment::Key(key) if is_plain_key(key) => {
+                    path.push('.');
+                    path.push_str(key);
+                }
+                PathSegment::Key(key) => {
+                    path.push_str(&format!("[{}]", Value::String(key.to_string())));
+                }
+                PathSegment::Index(index) => {
+                    path.push_str(&format!("[{}]", index));
+                }
+            }
+        }
+
+        path
+    }
+
+    /// Converts the path into a [JsonPath], for example `$.users[0].name`.
+    pub fn to_json_path(&self) -> Result<JsonPath, serde_json::Error> {
+        serde_json::from_value(Value::String(self.to_json_path_string()))
+    }
+}
+
+impl Display for ValidationPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_json_pointer())
+    }
+}
+
+impl<T: Into<PathSegment>, const U: usize> From<[T; U]> for ValidationPath {
+    fn from(value: [T; U]) -> Self {
+        ValidationPath {
+            segments: value.into_iter()
+                .map(|segment| segment.into())
+                .collect(),
+        }
+    }
+}
+
+/// Keys that only contain alphanumeric characters and underscores can use dot notation in a JSON
+/// path, all other keys are written using bracket notation.
+fn is_plain_key(key: &str) -> bool {
+    !key.is_empty()
+        && !key.starts_with(|char: char| char.is_ascii_digit())
+        && key.chars().all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::validation_path::{PathSegment, ValidationPath};
+
+    #[test]
+    fn root_path_is_formatted_correctly() {
+        let path = ValidationPath::new();
+
+        assert_eq!(path.to_json_pointer(), "");
+        assert_eq!(path.to_json_path_string(), "$");
+    }
+
+    #[test]
+    fn nested_path_is_formatted_correctly() {
+        let path = ValidationPath {
+            segments: vec![
+                PathSegment::Key("users".to_string()),
+                PathSegment::Index(0),
+                PathSegment::Key("name".to_string()),
+            ],
+        };
+
+        assert_eq!(path.to_json_pointer(), "/users/0/name");
+        assert_eq!(path.to_json_path_string(), "$.users[0].name");
+    }
+
+    #[test]
+    fn special_characters_are_escaped() {
+        let path = ValidationPath::from(["a/b", "m~n", "first name"]);
+
+        assert_eq!(path.to_json_pointer(), "/a~1b/m~0n/first name");
+        assert_eq!(path.to_json_path_string(), "$[\"a/b\"][\"m~n\"][\"first name\"]");
+    }
+}
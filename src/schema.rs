@@ -1,24 +1,90 @@
 pub mod schema_change;
+pub mod definitions;
+pub mod validation_scope;
 
 use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::Error as DeError;
 use serde_json::Value;
 use thiserror::Error;
+use crate::compiled_schema::CompiledSchema;
+use crate::report::validation_context::ValidationContext;
+use crate::report::validation_path::ValidationPath;
+use crate::schema::definitions::Definitions;
 use crate::schema::schema_change::SchemaChange;
-use crate::schema_type::SchemaType;
+use crate::schema::validation_scope::ValidationScope;
+use crate::schema_type::coercion::Coercion;
+use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
 
-#[derive(Debug, Error)]
+#[derive(Debug, PartialEq, Error)]
 pub enum SchemaValidationError {
     #[error("invalid schema value")]
     InvalidSchemaValue,
+
+    #[error("reference to unknown definition '{0}'")]
+    UnknownDefinition(String),
+
+    #[error("definitions refer to each other without nesting: {}", .0.join(" -> "))]
+    CyclicDefinition(Vec<String>),
+
+    #[error("default of field '{0}' does not match its type: {1}")]
+    InvalidFieldDefault(String, Box<SchemaTypeValidationError>),
+
+    #[error("example {1} of field '{0}' does not match its type: {2}")]
+    InvalidFieldExample(String, usize, Box<SchemaTypeValidationError>),
 }
 
 /// A schema encapsulates multiple version of the schema which are updated through migrations.
-#[derive(Debug, Serialize, Deserialize)]
+/// Named schemas in [Schema::definitions] can be referenced from any version using a [RefType].
+///
+/// [RefType]: crate::schema_type::advanced_type::ref_type::RefType
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Schema {
     version: u32,
     initial: SchemaType,
     changes: Vec<SchemaChange>,
+
+    #[serde(skip_serializing_if = "Definitions::is_empty")]
+    definitions: Definitions,
+
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    strict_objects: bool,
+}
+
+/// Used to deserialize a [Schema] before the references are checked.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSchema {
+    version: u32,
+    initial: SchemaType,
+    changes: Vec<SchemaChange>,
+
+    #[serde(default)]
+    definitions: Definitions,
+
+    #[serde(default)]
+    strict_objects: bool,
+}
+
+impl<'de> Deserialize<'de> for Schema {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawSchema::deserialize(deserializer)?;
+
+        let schema = Schema {
+            version: raw.version,
+            initial: raw.initial,
+            changes: raw.changes,
+            definitions: raw.definitions,
+            strict_objects: raw.strict_objects,
+        };
+
+        schema.check()
+            .map_err(D::Error::custom)?;
+
+        Ok(schema)
+    }
 }
 
 impl Schema {
@@ -26,6 +92,131 @@ impl Schema {
         self.version += 1;
         self.changes.push(change);
     }
+
+    /// Adds a named schema that can be referenced using a [RefType]. The definition is not added
+    /// if it refers to unknown definitions, would create a cycle or has a field with an invalid
+    /// default or example.
+    ///
+    /// [RefType]: crate::schema_type::advanced_type::ref_type::RefType
+    pub fn add_definition(
+        &mut self,
+        name: impl Into<String>,
+        schema_type: SchemaType,
+    ) -> Result<(), SchemaValidationError> {
+        let previous = self.definitions.clone();
+        self.definitions.insert(name, schema_type);
+
+        if let Err(error) = self.check() {
+            self.definitions = previous;
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    pub fn definitions(&self) -> &Definitions {
+        &self.definitions
+    }
+
+    /// The scope that values are validated in, which resolves references against the definitions
+    /// of this schema and applies its object policy, see [Schema::set_strict_objects]. Use this to
+    /// validate a [SchemaType] from this schema on its own, see [Validator::validate_in].
+    pub fn validation_scope(&self) -> ValidationScope<'_> {
+        ValidationScope::new(&self.definitions).with_strict_objects(self.strict_objects)
+    }
+
+    /// Sets whether objects that don't configure [AdditionalProperties] themselves should reject
+    /// keys that are not in the schema when validating through this schema. This also applies to
+    /// the [SchemaType::Object] shorthand. By default additional keys are allowed.
+    ///
+    /// Keys that another schema for the same object declares are not rejected. These are the keys
+    /// declared by the other variants of an [AllOfType], the discriminator of a [TaggedType], the
+    /// keys declared by the condition or a branch of an [IfType] and, for the dependent schemas of
+    /// an [ObjectType], the keys of the object itself. Schemas declare the keys they describe, like
+    /// the keys of an object or the keys a record accepts, so a key that none of them declares is
+    /// still rejected. Keys of `dependentRequired` and `dependentSchemas` are only known while
+    /// their trigger is present.
+    ///
+    /// [AdditionalProperties]: crate::schema_type::advanced_type::object_type::AdditionalProperties
+    /// [AllOfType]: crate::schema_type::advanced_type::all_of_type::AllOfType
+    /// [TaggedType]: crate::schema_type::advanced_type::tagged_type::TaggedType
+    /// [IfType]: crate::schema_type::advanced_type::if_type::IfType
+    /// [ObjectType]: crate::schema_type::advanced_type::object_type::ObjectType
+    pub fn set_strict_objects(&mut self, strict: bool) {
+        self.strict_objects = strict;
+    }
+
+    /// Returns whether objects reject unknown keys by default, see [Schema::set_strict_objects].
+    pub fn strict_objects(&self) -> bool {
+        self.strict_objects
+    }
+
+    /// The schema type of the latest version.
+    pub fn current(&self) -> &SchemaType {
+        self.changes.last()
+            .map(|change| change.new_schema())
+            .unwrap_or(&self.initial)
+    }
+
+    /// Prepares the latest version for validating many values, see [CompiledSchema].
+    pub fn compile(&self) -> CompiledSchema {
+        CompiledSchema::new(self.current(), &self.definitions, self.strict_objects)
+    }
+
+    /// Fills missing values with their defaults using the latest version, see
+    /// [SchemaType::apply_defaults].
+    pub fn apply_defaults(&self, value: &mut Value) -> Vec<ValidationPath> {
+        self.current().apply_defaults_in(value, self.validation_scope())
+    }
+
+    /// Converts and validates input where values might have been provided as strings using the
+    /// latest version, see [SchemaType::validate_and_coerce].
+    pub fn validate_and_coerce(&self, value: &mut Value) -> Result<Vec<Coercion>, Box<SchemaTypeValidationError>> {
+        self.current().validate_and_coerce_in(value, self.validation_scope())
+    }
+
+    /// Checks the references between the definitions and the schema types, and checks the
+    /// metadata of every [Field] once the references are known to resolve.
+    ///
+    /// [Field]: crate::schema_type::field::Field
+    fn check(&self) -> Result<(), SchemaValidationError> {
+        let schema_types = std::iter::once(&self.initial)
+            .chain(self.changes.iter().map(|change| change.new_schema()))
+            .collect::<Vec<&SchemaType>>();
+
+        self.definitions.check(schema_types.iter().copied())?;
+
+        let definitions = self.definitions.iter()
+            .map(|(_, schema_type)| schema_type);
+
+        let mut result = Ok(());
+
+        for schema_type in schema_types.into_iter().chain(definitions) {
+            schema_type.for_each_schema(&mut |schema_type| {
+                if let (Ok(()), SchemaType::Field(field)) = (&result, schema_type) {
+                    result = field.check_metadata_in(self.validation_scope());
+                }
+            });
+        }
+
+        result
+    }
+}
+
+/// Validates against the latest version in the scope of the schema, see
+/// [Schema::validation_scope].
+impl Validator for Schema {
+    type E = SchemaTypeValidationError;
+
+    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+        self.current().validate_in(value, self.validation_scope())
+    }
+}
+
+impl ReportingValidator for Schema {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        context.enter_scope(self.validation_scope(), |context| self.current().report(value, context));
+    }
 }
 
 impl From<SchemaType> for Schema {
@@ -34,6 +225,246 @@ impl From<SchemaType> for Schema {
             version: 0,
             initial: value,
             changes: vec![],
+            definitions: Definitions::default(),
+            strict_objects: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::schema::{Schema, SchemaValidationError};
+    use crate::schema_type::advanced_type::ref_type::RefType;
+    use crate::schema_type::basic_type::BasicType;
+    use crate::schema_type::SchemaType;
+    use crate::traits::reporting_validator::ReportingValidator;
+    use crate::traits::validator::Validator;
+
+    #[test]
+    fn schema_with_definitions_is_deserialized_and_validated() {
+        let schema: Schema = serde_json::from_value(json!({
+            "version": 0,
+            "initial": { "$": "ref", "name": "menu" },
+            "changes": [],
+            "definitions": {
+                "menu": [{
+                    "label": "filledString",
+                    "children": { "$": "optional", "type": { "$": "ref", "name": "menu" } },
+                }],
+            },
+        }))
+            .unwrap();
+
+        assert_eq!(schema.validate(&json!([
+            { "label": "File", "children": [{ "label": "Open" }, { "label": "Recent", "children": [] }] },
+            { "label": "Edit" },
+        ])), Ok(()));
+
+        assert!(schema.validate(&json!([
+            { "label": "File", "children": [{ "label": "" }] },
+        ])).is_err());
+    }
+
+    #[test]
+    fn schema_with_invalid_references_fails_to_deserialize() {
+        let result = serde_json::from_value::<Schema>(json!({
+            "version": 0,
+            "initial": { "$": "ref", "name": "missing" },
+            "changes": [],
+        }));
+        assert!(result.is_err());
+
+        let result = serde_json::from_value::<Schema>(json!({
+            "version": 0,
+            "initial": "string",
+            "changes": [],
+            "definitions": {
+                "loop": { "$": "allOf", "variants": [{ "$": "ref", "name": "loop" }] },
+            },
+        }));
+        assert!(result.unwrap_err().to_string().contains("loop -> loop"));
+    }
+
+    #[test]
+    fn schema_with_invalid_field_default_fails_to_deserialize() {
+        let schema = |default: serde_json::Value| serde_json::from_value::<Schema>(json!({
+            "version": 0,
+            "initial": {
+                "country": { "$": "ref", "name": "country" },
+            },
+            "changes": [],
+            "definitions": {
+                "country": {
+                    "?": { "$": "enum", "values": ["NL", "BE"] },
+                    "label": "Country",
+                    "hint": null,
+                    "default": default,
+                },
+            },
+        }));
+
+        assert!(schema(json!("NL")).is_ok());
+        assert_eq!(
+            schema(json!("DE")).unwrap_err().to_string(),
+            "default of field 'Country' does not match its type: No matching value. Expected one of: \"NL\", \"BE\""
+        );
+    }
+
+    fn strict_schema(initial: serde_json::Value) -> Schema {
+        let schema: Schema = serde_json::from_value(json!({
+            "version": 0,
+            "initial": initial,
+            "changes": [],
+            "strictObjects": true,
+        }))
+            .unwrap();
+
+        assert!(schema.strict_objects());
+        schema
+    }
+
+    #[test]
+    fn strict_objects_reject_unknown_keys() {
+        let schema = strict_schema(json!({
+            "name": "string",
+            "address": { "$": "object", "object": { "street": "string" } },
+            "meta": { "$": "object", "object": {}, "additionalProperties": true },
+        }));
+
+        let valid = json!({ "name": "", "address": { "street": "" }, "meta": { "any": 1 } });
+        assert_eq!(schema.validate(&valid), Ok(()));
+        assert_eq!(schema.compile().validate(&valid), Ok(()));
+
+        let invalid = json!({ "name": "", "address": { "street": "", "number": 1 }, "meta": {} });
+        assert_eq!(
+            schema.validate(&invalid).unwrap_err().to_string(),
+            "Unexpected object key: 'number'"
+        );
+        assert_eq!(schema.compile().validate(&invalid), schema.validate(&invalid));
+
+        let report = schema.validate_all(&json!({ "name": "", "nickname": "", "address": { "street": "" }, "meta": {} }));
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].instance_path.to_json_pointer(), "/nickname");
+
+        // The policy only applies while validating through the schema.
+        assert_eq!(schema.current().validate(&invalid), Ok(()));
+
+        let mut lenient = strict_schema(json!({ "name": "string" }));
+        lenient.set_strict_objects(false);
+        assert_eq!(lenient.validate(&json!({ "name": "", "nickname": "" })), Ok(()));
+        assert_eq!(serde_json::to_value(&lenient).unwrap().get("strictObjects"), None);
+        assert_eq!(serde_json::to_value(&schema).unwrap()["strictObjects"], json!(true));
+    }
+
+    #[test]
+    fn strict_objects_allow_keys_of_combined_schemas() {
+        let shape = strict_schema(json!({
+            "$": "tagged",
+            "discriminator": "kind",
+            "variants": {
+                "circle": { "radius": "number" },
+                "square": { "size": "number" },
+            },
+        }));
+
+        let person = strict_schema(json!({
+            "$": "allOf",
+            "variants": [
+                { "name": "string", "address": { "street": "string" } },
+                { "age": "number" },
+            ],
+        }));
+
+        let names = strict_schema(json!({
+            "$": "allOf",
+            "variants": [{ "name": "string" }, { "age": "number" }],
+        }));
+
+        let address = strict_schema(json!({
+            "$": "allOf",
+            "variants": [
+                { "country": "string", "state": { "$": "optional", "type": "string" } },
+                {
+                    "$": "if",
+                    "if": { "country": { "$": "const", "value": "US" } },
+                    "then": { "state": "filledString" },
+                },
+            ],
+        }));
+
+        let state = strict_schema(json!({
+            "$": "if",
+            "if": { "country": { "$": "const", "value": "US" } },
+            "then": { "state": "filledString" },
+            "else": { "country": "string" },
+        }));
+
+        let cases = [
+            (&shape, json!({ "kind": "circle", "radius": 1 }), true),
+            (&shape, json!({ "kind": "circle", "radius": 1, "size": 1 }), false),
+            (&person, json!({ "name": "Alice", "age": 30, "address": { "street": "" } }), true),
+            (&person, json!({ "name": "Alice", "age": 30, "address": { "street": "", "age": 30 } }), false),
+            (&person, json!({ "name": "Alice", "age": 30, "address": { "street": "" }, "nickname": "" }), false),
+            (&names, json!({ "name": "a", "age": 1 }), true),
+            (&names, json!({ "name": "a", "age": 1, "nickname": "" }), false),
+            (&address, json!({ "country": "US", "state": "CA" }), true),
+            (&address, json!({ "country": "US", "state": "" }), false),
+            (&address, json!({ "country": "NL" }), true),
+            (&state, json!({ "country": "US", "state": "CA" }), true),
+            (&state, json!({ "country": "US" }), false),
+            (&state, json!({ "country": "NL", "state": "" }), true),
+            (&state, json!({ "country": "NL", "nickname": "" }), false),
+        ];
+
+        for (schema, value, valid) in cases {
+            assert_eq!(schema.validate(&value).is_ok(), valid, "{}", value);
+            assert_eq!(schema.validate_all(&value).is_valid(), valid, "{}", value);
+            assert_eq!(schema.compile().validate(&value), schema.validate(&value), "{}", value);
         }
     }
+
+    #[test]
+    fn first_error_points_into_definitions() {
+        let schema: Schema = serde_json::from_value(json!({
+            "version": 0,
+            "initial": [{ "$": "ref", "name": "comment" }],
+            "changes": [],
+            "definitions": {
+                "comment": {
+                    "text": "filledString",
+                    "replies": [{ "$": "ref", "name": "comment" }],
+                },
+            },
+        }))
+            .unwrap();
+
+        let valid = json!([{ "text": "Hi", "replies": [] }]);
+        assert_eq!(schema.validate_first(&valid), Ok(()));
+
+        let invalid = json!([{ "text": "Hi", "replies": [{ "text": "", "replies": [] }] }]);
+        let error = schema.validate_first(&invalid).unwrap_err();
+
+        assert_eq!(error.instance_path.to_json_pointer(), "/0/replies/0/text");
+        assert_eq!(error.schema_path.to_json_pointer(), "/definitions/comment/text");
+        assert_eq!(schema.validate_all(&invalid).errors.first(), Some(&*error));
+    }
+
+    #[test]
+    fn definitions_are_checked_when_added() {
+        let mut schema = Schema::from(SchemaType::from(RefType::from("name")));
+
+        assert_eq!(
+            schema.add_definition("other", BasicType::String.into()),
+            Err(SchemaValidationError::UnknownDefinition("name".to_string()))
+        );
+        assert!(schema.definitions().get("other").is_none());
+
+        assert_eq!(schema.add_definition("name", BasicType::FilledString.into()), Ok(()));
+        assert_eq!(schema.validate(&json!("Alice")), Ok(()));
+        assert_eq!(
+            schema.add_definition("alias", RefType::from("alias").into()),
+            Err(SchemaValidationError::CyclicDefinition(vec!["alias".to_string(), "alias".to_string()]))
+        );
+    }
 }
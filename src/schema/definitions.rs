@@ -0,0 +1,231 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::schema::SchemaValidationError;
+use crate::schema_type::advanced_type::AdvancedType;
+use crate::schema_type::SchemaType;
+
+/// The maximum number of references that are followed while validating a single value. Recursive
+/// definitions can only go deeper when the value is nested deeper, so this only limits how deeply
+/// nested values can be and prevents running out of stack space.
+pub const MAX_REF_DEPTH: usize = 64;
+
+/// Named schemas that can be referenced using a [RefType]. The definitions are cheap to clone as
+/// they are shared between clones.
+///
+/// [RefType]: crate::schema_type::advanced_type::ref_type::RefType
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Definitions(Arc<HashMap<String, SchemaType>>);
+
+impl Definitions {
+    pub fn get(&self, name: &str) -> Option<&SchemaType> {
+        self.0.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &SchemaType)> {
+        self.0.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, schema_type: SchemaType) {
+        Arc::make_mut(&mut self.0).insert(name.into(), schema_type);
+    }
+
+    /// Checks that all references in the definitions and in the provided schemas refer to an
+    /// existing definition, and that definitions don't refer to themselves without validating a
+    /// nested value in between, as validating those would never end.
+    pub fn check<'a>(
+        &self,
+        schema_types: impl IntoIterator<Item = &'a SchemaType>,
+    ) -> Result<(), SchemaValidationError> {
+        for schema_type in schema_types {
+            self.check_known_refs(schema_type)?;
+        }
+
+        let mut names = self.0.keys()
+            .map(|name| name.as_str())
+            .collect::<Vec<&str>>();
+
+        names.sort();
+
+        let mut edges = HashMap::new();
+        for name in &names {
+            let definition = &self.0[*name];
+            self.check_known_refs(definition)?;
+
+            let mut unguarded_refs = vec![];
+            collect_refs(definition, false, &mut |ref_name, nested| {
+                if !nested {
+                    unguarded_refs.push(ref_name);
+                }
+            });
+
+            unguarded_refs.sort();
+            unguarded_refs.dedup();
+            edges.insert(*name, unguarded_refs);
+        }
+
+        let mut done = HashSet::new();
+        for name in names {
+            if let Some(cycle) = find_cycle(name, &edges, &mut vec![], &mut done) {
+                return Err(SchemaValidationError::CyclicDefinition(cycle));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_known_refs(&self, schema_type: &SchemaType) -> Result<(), SchemaValidationError> {
+        let mut unknown = None;
+        collect_refs(schema_type, false, &mut |ref_name, _| {
+            if unknown.is_none() && !self.0.contains_key(ref_name) {
+                unknown = Some(ref_name.to_string());
+            }
+        });
+
+        match unknown {
+            Some(name) => Err(SchemaValidationError::UnknownDefinition(name)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Calls the visitor for every reference in the schema, together with whether a nested value has
+/// been entered on the way to the reference.
+fn collect_refs<'a>(schema_type: &'a SchemaType, nested: bool, visitor: &mut impl FnMut(&'a str, bool)) {
+    if let Some(AdvancedType::Ref(ref_type)) = schema_type.as_advanced() {
+        visitor(&ref_type.name, nested);
+    }
+
+    for (child, child_nested) in schema_type.child_schemas() {
+        collect_refs(child, nested || child_nested, visitor);
+    }
+}
+
+/// Depth-first search for a cycle in the references between definitions, returning the names that
+/// make up the cycle.
+fn find_cycle<'a>(
+    name: &'a str,
+    edges: &HashMap<&'a str, Vec<&'a str>>,
+    path: &mut Vec<&'a str>,
+    done: &mut HashSet<&'a str>,
+) -> Option<Vec<String>> {
+    if let Some(position) = path.iter().position(|visited| *visited == name) {
+        let mut cycle = path[position..].iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<String>>();
+
+        cycle.push(name.to_string());
+        return Some(cycle);
+    }
+
+    if done.contains(name) {
+        return None;
+    }
+
+    path.push(name);
+    for next in edges.get(name).into_iter().flatten() {
+        if let Some(cycle) = find_cycle(next, edges, path, done) {
+            return Some(cycle);
+        }
+    }
+
+    path.pop();
+    done.insert(name);
+
+    None
+}
+
+impl Serialize for Definitions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Definitions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Definitions(Arc::new(HashMap::deserialize(deserializer)?)))
+    }
+}
+
+impl From<HashMap<String, SchemaType>> for Definitions {
+    fn from(value: HashMap<String, SchemaType>) -> Self {
+        Definitions(Arc::new(value))
+    }
+}
+
+impl<const U: usize> From<[(String, SchemaType); U]> for Definitions {
+    fn from(value: [(String, SchemaType); U]) -> Self {
+        Definitions::from(HashMap::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::schema::definitions::Definitions;
+    use crate::schema::SchemaValidationError;
+    use crate::schema_type::advanced_type::ref_type::RefType;
+    use crate::schema_type::SchemaType;
+
+    fn parse_definitions(value: serde_json::Value) -> Definitions {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn recursion_through_nested_values_is_allowed() {
+        let definitions = parse_definitions(json!({
+            "comment": {
+                "text": "string",
+                "replies": [{ "$": "ref", "name": "comment" }],
+            },
+            "node": {
+                "$": "anyOf",
+                "variants": ["string", { "$": "tuple", "items": [], "rest": { "$": "ref", "name": "node" } }],
+            },
+        }));
+
+        assert_eq!(definitions.check([]), Ok(()));
+    }
+
+    #[test]
+    fn cycles_without_nesting_are_rejected() {
+        let definitions = parse_definitions(json!({
+            "a": { "$": "ref", "name": "b" },
+            "b": { "$": "optional", "type": { "$": "ref", "name": "a" } },
+        }));
+
+        assert_eq!(
+            definitions.check([]),
+            Err(SchemaValidationError::CyclicDefinition(vec![
+                "a".to_string(),
+                "b".to_string(),
+                "a".to_string(),
+            ]))
+        );
+
+        let definitions = parse_definitions(json!({
+            "self": { "$": "anyOf", "variants": ["null", { "$": "ref", "name": "self" }] },
+        }));
+
+        assert_eq!(
+            definitions.check([]),
+            Err(SchemaValidationError::CyclicDefinition(vec!["self".to_string(), "self".to_string()]))
+        );
+    }
+
+    #[test]
+    fn unknown_references_are_rejected() {
+        let definitions = parse_definitions(json!({
+            "a": ["string"],
+        }));
+
+        let root = SchemaType::from(RefType::from("b"));
+
+        assert_eq!(definitions.check([&root]), Err(SchemaValidationError::UnknownDefinition("b".to_string())));
+        assert_eq!(definitions.check([&SchemaType::from(RefType::from("a"))]), Ok(()));
+    }
+}
@@ -6,3 +6,9 @@ use crate::schema_type::SchemaType;
 pub struct SchemaChange {
     new_schema: SchemaType,
 }
+
+impl SchemaChange {
+    pub fn new_schema(&self) -> &SchemaType {
+        &self.new_schema
+    }
+}
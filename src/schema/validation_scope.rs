@@ -0,0 +1,145 @@
+use serde_json::{Map, Value};
+use crate::schema::definitions::{Definitions, MAX_REF_DEPTH};
+use crate::schema_type::advanced_type::object_type::SharedKeys;
+use crate::schema_type::advanced_type::ref_type::RefTypeError;
+use crate::schema_type::SchemaType;
+
+/// The state that validation depends on besides the schema itself: the [Definitions] that
+/// references are resolved against, the number of references that have been followed to get to
+/// the current value and whether objects reject unknown keys. The scope is passed down explicitly
+/// while validating, see [Validator::validate_in], so it works the same on any thread and for any
+/// nested [SchemaType].
+///
+/// The default scope has no definitions, so every reference results in
+/// [RefTypeError::UnknownDefinition], and allows unknown keys.
+///
+/// [Validator::validate_in]: crate::traits::validator::Validator::validate_in
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidationScope<'a> {
+    definitions: Option<&'a Definitions>,
+    depth: usize,
+    strict_objects: bool,
+    shared: Option<SharedFrame<'a>>,
+}
+
+/// Keys that are shared for the current value, see [SharedKeys]. The frames are linked, as all
+/// keys that are shared for the value apply when combinators are nested.
+#[derive(Debug, Clone, Copy)]
+struct SharedFrame<'a> {
+    keys: SharedKeys<'a>,
+    parent: Option<&'a SharedFrame<'a>>,
+}
+
+impl<'a> ValidationScope<'a> {
+    /// Creates a scope that resolves references against the provided definitions.
+    pub fn new(definitions: &'a Definitions) -> Self {
+        ValidationScope {
+            definitions: Some(definitions),
+            ..ValidationScope::default()
+        }
+    }
+
+    /// Returns the scope with objects that don't configure [AdditionalProperties] themselves
+    /// rejecting unknown keys if `strict_objects` is true, see [Schema::set_strict_objects].
+    ///
+    /// [AdditionalProperties]: crate::schema_type::advanced_type::object_type::AdditionalProperties
+    /// [Schema::set_strict_objects]: crate::schema::Schema::set_strict_objects
+    pub fn with_strict_objects(self, strict_objects: bool) -> Self {
+        ValidationScope {
+            strict_objects,
+            ..self
+        }
+    }
+
+    pub fn definitions(&self) -> Option<&'a Definitions> {
+        self.definitions
+    }
+
+    /// Returns whether objects reject unknown keys by default, see
+    /// [ValidationScope::with_strict_objects].
+    pub fn strict_objects(&self) -> bool {
+        self.strict_objects
+    }
+
+    /// The number of references that have been followed to get to the current value.
+    pub(crate) fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the scope with the given number of references followed, see
+    /// [ValidationScope::depth].
+    pub(crate) fn at_depth(self, depth: usize) -> Self {
+        ValidationScope {
+            depth,
+            ..self
+        }
+    }
+
+    /// Returns the scope with only the given keys shared for the current value.
+    pub(crate) fn with_shared_keys(self, keys: SharedKeys<'a>) -> Self {
+        ValidationScope {
+            shared: Some(SharedFrame { keys, parent: None }),
+            ..self
+        }
+    }
+
+    /// Runs the provided closure with the given keys shared for the current value, besides the
+    /// keys that are already shared. Objects that validate the same value inside the closure
+    /// don't reject these keys in strict mode.
+    pub(crate) fn share<R>(self, keys: SharedKeys, f: impl FnOnce(ValidationScope) -> R) -> R {
+        if !self.strict_objects {
+            return f(self);
+        }
+
+        let parent = self.shared;
+
+        f(ValidationScope {
+            shared: Some(SharedFrame { keys, parent: parent.as_ref() }),
+            ..self
+        })
+    }
+
+    /// Returns the scope for a value nested in the current value, like an item of an array or a
+    /// value of an object. The keys that are shared for the current value don't apply to it.
+    pub(crate) fn nested(self) -> Self {
+        ValidationScope {
+            shared: None,
+            ..self
+        }
+    }
+
+    /// Returns whether the key of the current value is shared, see [ValidationScope::share].
+    pub(crate) fn is_shared(&self, key: &str, target_map: &Map<String, Value>) -> bool {
+        let mut frame = self.shared.as_ref();
+
+        while let Some(SharedFrame { keys, parent }) = frame {
+            if keys.contains(key, target_map, *self) {
+                return true;
+            }
+
+            frame = *parent;
+        }
+
+        false
+    }
+
+    /// Returns the definition with the given name, together with the scope to validate it in.
+    /// Fails if the definition does not exist or if [MAX_REF_DEPTH] references have already been
+    /// followed.
+    pub(crate) fn resolve(self, name: &str) -> Result<(&'a SchemaType, ValidationScope<'a>), RefTypeError> {
+        let Some(definition) = self.definitions.and_then(|definitions| definitions.get(name)) else {
+            return Err(RefTypeError::UnknownDefinition(name.to_string()));
+        };
+
+        if self.depth >= MAX_REF_DEPTH {
+            return Err(RefTypeError::MaxDepthExceeded(MAX_REF_DEPTH));
+        }
+
+        let scope = ValidationScope {
+            depth: self.depth + 1,
+            ..self
+        };
+
+        Ok((definition, scope))
+    }
+}
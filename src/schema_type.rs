@@ -1,22 +1,43 @@
 use crate::schema_type::advanced_type::{AdvancedType, AdvancedTypeValidationError};
 use crate::schema_type::basic_type::{BasicType, BasicTypeValidationError};
+use crate::report::validation_context::ValidationContext;
+use crate::traits::reporting_validator::ReportingValidator;
 use crate::traits::validator::Validator;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
 use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
 use thiserror::Error;
 use crate::schema_type::advanced_type::advanced_string_type::AdvancedStringType;
+use crate::schema_type::advanced_type::advanced_number_type::AdvancedNumberType;
 use crate::schema_type::advanced_type::any_of_type::AnyOfType;
-use crate::schema_type::advanced_type::array_type::ArrayType;
-use crate::schema_type::advanced_type::object_type::ObjectType;
+use crate::schema_type::advanced_type::array_type::{ArrayType, ArrayTypeError};
+use crate::schema_type::advanced_type::object_type::{AdditionalProperties, ObjectType, ObjectTypeError};
 use crate::schema_type::advanced_type::optional_type::OptionalType;
-use crate::schema_type::advanced_type::tuple_type::TupleType;
+use crate::schema_type::advanced_type::tuple_type::{TupleError, TupleType};
+use crate::schema_type::advanced_type::const_type::ConstType;
+use crate::schema_type::advanced_type::enum_type::EnumType;
+use crate::schema_type::advanced_type::tagged_type::TaggedType;
+use crate::schema_type::advanced_type::all_of_type::AllOfType;
+use crate::schema_type::advanced_type::one_of_type::OneOfType;
+use crate::schema_type::advanced_type::not_type::NotType;
+use crate::schema_type::advanced_type::record_type::RecordType;
+use crate::schema_type::advanced_type::ref_type::RefType;
+use crate::schema_type::advanced_type::custom_type::{CustomType, CustomValidationError};
+use crate::schema_type::advanced_type::if_type::IfType;
 use crate::schema_type::field::Field;
+use crate::schema_type::error_message::WithErrorMessage;
+use crate::traits::error_code::{serialize_error_code, ErrorCode};
+use crate::schema::validation_scope::ValidationScope;
 
 pub mod advanced_type;
 pub mod basic_type;
 pub mod field;
+pub mod pattern;
+pub mod string_format;
+pub mod coercion;
+pub mod error_message;
+mod defaults;
 
 #[derive(Debug, Error, PartialEq)]
 pub enum SchemaTypeValidationError {
@@ -25,14 +46,54 @@ pub enum SchemaTypeValidationError {
 
     #[error("{0}")]
     AdvancedTypeValidationError(#[from] AdvancedTypeValidationError),
+
+    #[error("{0}")]
+    CustomValidationError(#[from] CustomValidationError),
+
+    /// The wrapped error with the message that was set in the schema, see [ErrorMessage](error_message::ErrorMessage).
+    #[error("{0}")]
+    CustomMessage(String, Box<SchemaTypeValidationError>),
 }
 
+impl ErrorCode for SchemaTypeValidationError {
+    fn code(&self) -> &'static str {
+        match self {
+            SchemaTypeValidationError::BasicTypeValidationError(error) => error.code(),
+            SchemaTypeValidationError::AdvancedTypeValidationError(error) => error.code(),
+            SchemaTypeValidationError::CustomValidationError(error) => error.code(),
+            SchemaTypeValidationError::CustomMessage(_, error) => error.code(),
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            SchemaTypeValidationError::BasicTypeValidationError(error) => error.params(),
+            SchemaTypeValidationError::AdvancedTypeValidationError(error) => error.params(),
+            SchemaTypeValidationError::CustomValidationError(error) => error.params(),
+            SchemaTypeValidationError::CustomMessage(_, error) => error.params(),
+        }
+    }
+
+    fn custom_message(&self) -> Option<&str> {
+        match self {
+            SchemaTypeValidationError::BasicTypeValidationError(_) => None,
+            SchemaTypeValidationError::AdvancedTypeValidationError(error) => error.custom_message(),
+            SchemaTypeValidationError::CustomValidationError(_) => None,
+            SchemaTypeValidationError::CustomMessage(message, _) => Some(message),
+        }
+    }
+}
+
+serialize_error_code!(SchemaTypeValidationError);
+
 /// Root schema type that encompasses all the different types that can be validated.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged, rename_all = "camelCase")]
 pub enum SchemaType {
     Basic(BasicType),
     Field(Field),
+    // Tried before `Advanced`, which would otherwise accept the type and ignore the message.
+    WithErrorMessage(WithErrorMessage),
     Advanced(AdvancedType),
     Array((Box<SchemaType>,)),
     Tuple(Vec<SchemaType>),
@@ -44,14 +105,13 @@ impl Display for SchemaType {
         match self {
             SchemaType::Basic(basic_type) => Display::fmt(basic_type, f),
             SchemaType::Field(field) => Display::fmt(field, f),
+            SchemaType::WithErrorMessage(with_error_message) => Display::fmt(with_error_message, f),
             SchemaType::Advanced(advanced_type) => Display::fmt(advanced_type, f),
             SchemaType::Array(item) => {
                 write!(f, "array filled with '{}'", item.0)
             }
             SchemaType::Tuple(items) => {
-                let tuple_type = TupleType {
-                    items: items.to_vec(),
-                };
+                let tuple_type = TupleType::from(items.to_vec());
 
                 Display::fmt(&tuple_type, f)
             }
@@ -65,40 +125,209 @@ impl Display for SchemaType {
 impl Validator for SchemaType {
     type E = SchemaTypeValidationError;
 
-    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
         match self {
             SchemaType::Basic(basic_type) => Ok(basic_type.validate(value)?),
-            SchemaType::Field(field) => field.validate(value),
-            SchemaType::Advanced(advanced_type) => Ok(advanced_type.validate(value)?),
+            SchemaType::Field(field) => field.validate_in(value, scope),
+            SchemaType::WithErrorMessage(with_error_message) => with_error_message.validate_in(value, scope),
+            SchemaType::Advanced(advanced_type) => Ok(advanced_type.validate_in(value, scope)?),
             SchemaType::Array(item) => {
                 let array_type = ArrayType {
                     require_filled: false,
-                    items: item.0.clone(),
+                    ..ArrayType::from(*item.0.clone())
                 };
 
-                Ok(array_type.validate(value)
+                Ok(array_type.validate_in(value, scope)
                     .map_err(|error| SchemaTypeValidationError::AdvancedTypeValidationError(AdvancedTypeValidationError::ArrayError(error)))?)
             }
             SchemaType::Tuple(items) => {
-                let tuple_type = TupleType {
-                    items: items.to_vec()
-                };
+                let tuple_type = TupleType::from(items.to_vec());
 
-                Ok(tuple_type.validate(value)
+                Ok(tuple_type.validate_in(value, scope)
                     .map_err(|error| SchemaTypeValidationError::AdvancedTypeValidationError(AdvancedTypeValidationError::TupleError(error)))?)
             }
             SchemaType::Object(map) => {
-                let object_type = ObjectType {
-                    object: map.clone(),
-                };
+                let object_type = ObjectType::from(map.clone());
 
-                Ok(object_type.validate(value)
+                Ok(object_type.validate_in(value, scope)
                     .map_err(|error| SchemaTypeValidationError::AdvancedTypeValidationError(AdvancedTypeValidationError::ObjectError(error)))?)
             }
         }
     }
 }
 
+impl ReportingValidator for SchemaType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        match self {
+            SchemaType::Basic(basic_type) => basic_type.report(value, context),
+            SchemaType::Field(field) => field.report(value, context),
+            SchemaType::WithErrorMessage(with_error_message) => with_error_message.report(value, context),
+            SchemaType::Advanced(advanced_type) => advanced_type.report(value, context),
+            SchemaType::Array(item) => {
+                let Value::Array(items) = value else {
+                    context.report(AdvancedTypeValidationError::ArrayError(ArrayTypeError::NotAnArray));
+                    return;
+                };
+
+                context.enter_schema(0, |context| {
+                    ArrayType::report_items(&item.0, items, context);
+                });
+            }
+            SchemaType::Tuple(items) => {
+                let Value::Array(value_items) = value else {
+                    context.report(AdvancedTypeValidationError::TupleError(TupleError::NotAnArray));
+                    return;
+                };
+
+                if let Err(error) = TupleType::validate_length(items, false, value_items.len()) {
+                    context.report(AdvancedTypeValidationError::TupleError(error));
+                }
+
+                TupleType::report_items(items, value_items, context);
+            }
+            SchemaType::Object(map) => {
+                let Value::Object(target_map) = value else {
+                    context.report(AdvancedTypeValidationError::ObjectError(ObjectTypeError::NotAnObject));
+                    return;
+                };
+
+                ObjectType::report_keys(map, target_map, context);
+                ObjectType::report_additional_keys(|key, _| map.contains_key(key), None, target_map, context);
+            }
+        }
+    }
+}
+
+impl SchemaType {
+    /// Returns the advanced type, also when it has an [ErrorMessage](error_message::ErrorMessage).
+    pub(crate) fn as_advanced(&self) -> Option<&AdvancedType> {
+        match self {
+            SchemaType::Advanced(advanced_type)
+            | SchemaType::WithErrorMessage(WithErrorMessage { kind: advanced_type, .. }) => Some(advanced_type),
+            _ => None,
+        }
+    }
+
+    /// Returns whether this schema declares the key for the given object, when it validates the
+    /// object itself. Only schemas that describe keys declare them, like the [SchemaType::Object]
+    /// shorthand, an [ObjectType] or a [RecordType] whose key constraint accepts the key. Schemas
+    /// that combine other schemas declare the keys of the schemas they apply. This decides which
+    /// keys are shared with strict objects, see [Schema::set_strict_objects].
+    ///
+    /// [Schema::set_strict_objects]: crate::schema::Schema::set_strict_objects
+    pub(crate) fn declares_key(&self, key: &str, target_map: &Map<String, Value>, scope: ValidationScope) -> bool {
+        let advanced_type = match self {
+            SchemaType::Field(field) => return field.field_type().declares_key(key, target_map, scope),
+            SchemaType::Object(map) => return map.contains_key(key),
+            SchemaType::Basic(_)
+            | SchemaType::Array(_)
+            | SchemaType::Tuple(_) => return false,
+            SchemaType::Advanced(advanced_type)
+            | SchemaType::WithErrorMessage(WithErrorMessage { kind: advanced_type, .. }) => advanced_type,
+        };
+
+        match advanced_type {
+            AdvancedType::Object(object_type) => object_type.is_known_key(key, target_map, scope),
+            AdvancedType::AnyOf(AnyOfType { variants })
+            | AdvancedType::AllOf(AllOfType { variants })
+            | AdvancedType::OneOf(OneOfType { variants }) => variants.iter()
+                .any(|variant| variant.declares_key(key, target_map, scope)),
+            AdvancedType::Optional(OptionalType { kind }) => kind.declares_key(key, target_map, scope),
+            AdvancedType::If(if_type) => if_type.declares_key(key, target_map, scope),
+            AdvancedType::Tagged(tagged_type) => tagged_type.declares_key(key, target_map, scope),
+            AdvancedType::Record(record_type) => record_type.keys.as_ref()
+                .is_none_or(|keys| keys.validate_key(key).is_ok()),
+            AdvancedType::Ref(ref_type) => scope.resolve(&ref_type.name)
+                .is_ok_and(|(definition, scope)| definition.declares_key(key, target_map, scope)),
+            AdvancedType::String(_)
+            | AdvancedType::Number(_)
+            | AdvancedType::Const(_)
+            | AdvancedType::Enum(_)
+            | AdvancedType::Custom(_)
+            | AdvancedType::Array(_)
+            | AdvancedType::Tuple(_)
+            | AdvancedType::Not(_) => false,
+        }
+    }
+
+    /// Returns the schemas that are directly nested in this schema, together with whether the
+    /// nested schema validates a nested value (like an item of an array) or the same value (like a
+    /// variant of [AnyOfType]).
+    pub(crate) fn child_schemas(&self) -> Vec<(&SchemaType, bool)> {
+        let (children, nested): (Vec<&SchemaType>, bool) = match self {
+            SchemaType::Basic(_) => (vec![], false),
+            SchemaType::Field(field) => (vec![field.field_type()], false),
+            SchemaType::Array(item) => (vec![&item.0], true),
+            SchemaType::Tuple(items) => (items.iter().collect(), true),
+            SchemaType::Object(map) => (map.values().collect(), true),
+            SchemaType::WithErrorMessage(WithErrorMessage { kind: advanced_type, .. })
+            | SchemaType::Advanced(advanced_type) => match advanced_type {
+                AdvancedType::String(_)
+                | AdvancedType::Number(_)
+                | AdvancedType::Const(_)
+                | AdvancedType::Enum(_)
+                | AdvancedType::Ref(_)
+                | AdvancedType::Custom(_) => (vec![], false),
+                AdvancedType::AnyOf(AnyOfType { variants })
+                | AdvancedType::AllOf(AllOfType { variants })
+                | AdvancedType::OneOf(OneOfType { variants }) => (variants.iter().collect(), false),
+                AdvancedType::If(if_type) => {
+                    let branches = std::iter::once(&*if_type.condition)
+                        .chain(if_type.then.as_deref())
+                        .chain(if_type.otherwise.as_deref())
+                        .collect();
+
+                    (branches, false)
+                }
+                AdvancedType::Optional(OptionalType { kind })
+                | AdvancedType::Not(NotType { kind }) => (vec![kind], false),
+                AdvancedType::Tagged(tagged_type) => (tagged_type.variants.values().collect(), false),
+                AdvancedType::Tuple(tuple_type) => {
+                    let items = tuple_type.items.iter()
+                        .chain(tuple_type.rest.as_deref())
+                        .collect();
+
+                    (items, true)
+                }
+                AdvancedType::Array(array_type) => {
+                    let items = std::iter::once(&*array_type.items)
+                        .chain(array_type.contains.as_deref())
+                        .collect();
+
+                    (items, true)
+                }
+                AdvancedType::Object(object_type) => {
+                    let additional_properties = match &object_type.additional_properties {
+                        Some(AdditionalProperties::Schema(schema)) => Some(&**schema),
+                        _ => None,
+                    };
+
+                    // Dependent schemas apply to the object itself instead of to one of its values.
+                    return object_type.object.values()
+                        .chain(additional_properties)
+                        .map(|child| (child, true))
+                        .chain(object_type.dependent_schemas.values().map(|child| (child, false)))
+                        .collect();
+                }
+                AdvancedType::Record(record_type) => (vec![&*record_type.values], true),
+            },
+        };
+
+        children.into_iter()
+            .map(|child| (child, nested))
+            .collect()
+    }
+
+    /// Calls the provided closure for this schema and every schema that is nested in it.
+    pub(crate) fn for_each_schema<'a>(&'a self, f: &mut impl FnMut(&'a SchemaType)) {
+        f(self);
+
+        for (child, _) in self.child_schemas() {
+            child.for_each_schema(f);
+        }
+    }
+}
+
 impl From<BasicType> for SchemaType {
     fn from(value: BasicType) -> Self {
         SchemaType::Basic(value)
@@ -141,6 +370,12 @@ impl From<AdvancedStringType> for SchemaType {
     }
 }
 
+impl From<AdvancedNumberType> for SchemaType {
+    fn from(value: AdvancedNumberType) -> Self {
+        SchemaType::Advanced(value.into())
+    }
+}
+
 impl From<AnyOfType> for SchemaType {
     fn from(value: AnyOfType) -> Self {
         SchemaType::Advanced(value.into())
@@ -171,6 +406,66 @@ impl From<OptionalType> for SchemaType {
     }
 }
 
+impl From<ConstType> for SchemaType {
+    fn from(value: ConstType) -> Self {
+        SchemaType::Advanced(value.into())
+    }
+}
+
+impl From<EnumType> for SchemaType {
+    fn from(value: EnumType) -> Self {
+        SchemaType::Advanced(value.into())
+    }
+}
+
+impl From<TaggedType> for SchemaType {
+    fn from(value: TaggedType) -> Self {
+        SchemaType::Advanced(value.into())
+    }
+}
+
+impl From<AllOfType> for SchemaType {
+    fn from(value: AllOfType) -> Self {
+        SchemaType::Advanced(value.into())
+    }
+}
+
+impl From<OneOfType> for SchemaType {
+    fn from(value: OneOfType) -> Self {
+        SchemaType::Advanced(value.into())
+    }
+}
+
+impl From<NotType> for SchemaType {
+    fn from(value: NotType) -> Self {
+        SchemaType::Advanced(value.into())
+    }
+}
+
+impl From<RecordType> for SchemaType {
+    fn from(value: RecordType) -> Self {
+        SchemaType::Advanced(value.into())
+    }
+}
+
+impl From<RefType> for SchemaType {
+    fn from(value: RefType) -> Self {
+        SchemaType::Advanced(value.into())
+    }
+}
+
+impl From<CustomType> for SchemaType {
+    fn from(value: CustomType) -> Self {
+        SchemaType::Advanced(value.into())
+    }
+}
+
+impl From<IfType> for SchemaType {
+    fn from(value: IfType) -> Self {
+        SchemaType::Advanced(value.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::schema_type::advanced_type::advanced_string_type::AdvancedStringType;
@@ -181,6 +476,10 @@ mod tests {
     use std::collections::HashMap;
     use crate::schema_type::advanced_type::AdvancedTypeValidationError;
     use crate::schema_type::advanced_type::object_type::ObjectTypeError;
+    use crate::report::ReportedError;
+    use crate::report::validation_path::{PathSegment, ValidationPath};
+    use crate::schema_type::advanced_type::advanced_string_type::StringValidationError;
+    use crate::traits::reporting_validator::ReportingValidator;
 
     #[test]
     fn basic_schema_type_can_be_deserialized() {
@@ -340,6 +639,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn advanced_number_type_is_resolved_correctly() {
+        let value: SchemaType = serde_json::from_value(json!({
+            "quantity": {
+                "$": "number",
+                "minimum": 1,
+                "maximum": 99,
+                "integer": true
+            }
+        }))
+            .unwrap();
+
+        assert_eq!(value.validate(&json!({ "quantity": 1 })), Ok(()));
+        assert_eq!(value.validate(&json!({ "quantity": 99 })), Ok(()));
+        assert!(value.validate(&json!({ "quantity": 0 })).is_err());
+        assert!(value.validate(&json!({ "quantity": 100 })).is_err());
+        assert!(value.validate(&json!({ "quantity": 1.5 })).is_err());
+    }
+
     #[test]
     fn tuple_shorthand_is_resolved_correctly() {
         let value: SchemaType = serde_json::from_value(json!([
@@ -361,4 +679,141 @@ mod tests {
         assert!(value.validate(&json!([""])).is_err());
         assert!(value.validate(&json!(["", 10, ""])).is_err());
     }
+
+    #[test]
+    fn validate_all_reports_every_error_in_the_document() {
+        let value: SchemaType = serde_json::from_value(json!({
+            "name": { "$": "string" },
+            "age": "number",
+            "tags": ["string"],
+            "nickname": {
+                "$": "optional",
+                "type": "string"
+            },
+        }))
+            .unwrap();
+
+        let report = value.validate_all(&json!({
+            "name": "",
+            "tags": ["a", 10, true],
+            "nickname": 10,
+        }));
+
+        assert_eq!(report.errors, vec![
+            ReportedError {
+                instance_path: ValidationPath::new(),
+                schema_path: ValidationPath::from(["age"]),
+                error: SchemaTypeValidationError::AdvancedTypeValidationError(
+                    AdvancedTypeValidationError::ObjectError(
+                        ObjectTypeError::MissingObjectKey("age".to_string())
+                    )
+                ),
+            },
+            ReportedError {
+                instance_path: ValidationPath::from(["name"]),
+                schema_path: ValidationPath::from(["name"]),
+                error: SchemaTypeValidationError::AdvancedTypeValidationError(
+                    AdvancedTypeValidationError::StringValidationError(
+                        StringValidationError::RequireFilled
+                    )
+                ),
+            },
+            ReportedError {
+                instance_path: ValidationPath::from(["nickname"]),
+                schema_path: ValidationPath::from(["nickname", "type"]),
+                error: BasicTypeValidationError::IncorrectType(BasicType::String, json!(10)).into(),
+            },
+            ReportedError {
+                instance_path: ValidationPath::from([PathSegment::from("tags"), PathSegment::from(1)]),
+                schema_path: ValidationPath::from([PathSegment::from("tags"), PathSegment::from(0)]),
+                error: BasicTypeValidationError::IncorrectType(BasicType::String, json!(10)).into(),
+            },
+            ReportedError {
+                instance_path: ValidationPath::from([PathSegment::from("tags"), PathSegment::from(2)]),
+                schema_path: ValidationPath::from([PathSegment::from("tags"), PathSegment::from(0)]),
+                error: BasicTypeValidationError::IncorrectType(BasicType::String, json!(true)).into(),
+            },
+        ]);
+    }
+
+    #[test]
+    fn reported_errors_point_into_advanced_types() {
+        let value: SchemaType = serde_json::from_value(json!({
+            "$": "object",
+            "object": {
+                "users": {
+                    "$": "array",
+                    "items": {
+                        "?": ["string", "number"],
+                        "label": "User"
+                    }
+                }
+            }
+        }))
+            .unwrap();
+
+        let report = value.validate_all(&json!({
+            "users": [["Alice", 10], ["Bob", "ten"]]
+        }));
+
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].instance_path.to_json_pointer(), "/users/1/1");
+        assert_eq!(report.errors[0].schema_path.to_json_pointer(), "/object/users/items/?/1");
+    }
+
+    #[test]
+    fn first_error_includes_its_location() {
+        let value: SchemaType = serde_json::from_value(json!({
+            "users": {
+                "$": "array",
+                "items": ["string", "number"],
+            },
+        }))
+            .unwrap();
+
+        assert_eq!(value.validate_first(&json!({ "users": [["Alice", 10]] })), Ok(()));
+
+        let invalid = json!({ "users": [["Alice", 10], ["Bob", "ten"], [1, 2]] });
+        let error = value.validate_first(&invalid).unwrap_err();
+
+        assert_eq!(error.instance_path.to_json_pointer(), "/users/1/1");
+        assert_eq!(error.schema_path.to_json_pointer(), "/users/items/1");
+        assert!(value.validate(&invalid).is_err());
+
+        let report = value.validate_all(&invalid);
+        assert_eq!(report.errors.len(), 2);
+        assert_eq!(&report.errors[0], &*error);
+    }
+
+    #[test]
+    fn validate_all_agrees_with_validate_on_valid_documents() {
+        let value: SchemaType = serde_json::from_value(json!({
+            "name": "string",
+            "point": ["number", "number"],
+            "kind": {
+                "$": "anyOf",
+                "variants": ["string", "null"]
+            },
+        }))
+            .unwrap();
+
+        let document = json!({
+            "name": "Alice",
+            "point": [1, 2],
+            "kind": null,
+        });
+
+        assert_eq!(value.validate(&document), Ok(()));
+        assert!(value.validate_all(&document).is_valid());
+    }
+
+    #[test]
+    fn validate_all_checks_tuple_items_when_length_is_incorrect() {
+        let value: SchemaType = serde_json::from_value(json!(["string", "number"])).unwrap();
+
+        let report = value.validate_all(&json!([10]));
+
+        assert_eq!(report.errors.len(), 2);
+        assert!(report.into_result().is_err());
+    }
 }
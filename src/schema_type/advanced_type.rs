@@ -1,46 +1,96 @@
 pub mod advanced_string_type;
+pub mod advanced_number_type;
 pub mod any_of_type;
 pub mod optional_type;
 pub mod tuple_type;
 pub mod array_type;
 pub mod object_type;
+pub mod const_type;
+pub mod enum_type;
+pub mod tagged_type;
+pub mod all_of_type;
+pub mod one_of_type;
+pub mod not_type;
+pub mod record_type;
+pub mod ref_type;
+pub mod custom_type;
+pub mod if_type;
 
 use crate::schema_type::advanced_type::advanced_string_type::{
     AdvancedStringType, StringValidationError,
 };
+use crate::schema_type::advanced_type::advanced_number_type::{
+    AdvancedNumberType, NumberValidationError,
+};
 use crate::schema_type::advanced_type::any_of_type::{AnyOfType, AnyOfTypeError};
 use crate::schema_type::advanced_type::optional_type::OptionalType;
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::const_type::{ConstType, ConstTypeError};
+use crate::schema_type::advanced_type::enum_type::{EnumType, EnumTypeError};
+use crate::schema_type::advanced_type::tagged_type::{TaggedType, TaggedTypeError};
+use crate::schema_type::advanced_type::all_of_type::{AllOfType, AllOfTypeError};
+use crate::schema_type::advanced_type::one_of_type::{OneOfType, OneOfTypeError};
+use crate::schema_type::advanced_type::not_type::{NotType, NotTypeError};
+use crate::schema_type::advanced_type::record_type::{RecordType, RecordTypeError};
+use crate::schema_type::advanced_type::ref_type::{RefType, RefTypeError};
+use crate::schema_type::advanced_type::custom_type::CustomType;
+use crate::schema_type::advanced_type::if_type::{IfType, IfTypeError};
 use crate::schema_type::SchemaTypeValidationError;
+use crate::traits::reporting_validator::ReportingValidator;
 use crate::traits::validator::Validator;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
 use std::fmt::{Debug, Display, Formatter};
 use thiserror::Error;
 use crate::schema_type::advanced_type::array_type::{ArrayType, ArrayTypeError};
 use crate::schema_type::advanced_type::object_type::{ObjectType, ObjectTypeError};
 use crate::schema_type::advanced_type::tuple_type::{TupleError, TupleType};
+use crate::traits::error_code::{serialize_error_code, ErrorCode};
+use crate::schema::validation_scope::ValidationScope;
 
 /// Types that require more configuration than just checking if the type matches.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "$", rename_all = "camelCase")]
 pub enum AdvancedType {
     String(AdvancedStringType),
+    Number(AdvancedNumberType),
     AnyOf(AnyOfType),
     Tuple(TupleType),
     Array(ArrayType),
     Object(ObjectType),
     Optional(OptionalType),
+    Const(ConstType),
+    Enum(EnumType),
+    Tagged(TaggedType),
+    AllOf(AllOfType),
+    OneOf(OneOfType),
+    Not(NotType),
+    Record(RecordType),
+    Ref(RefType),
+    Custom(CustomType),
+    If(IfType),
 }
 
 impl Display for AdvancedType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             AdvancedType::String(advanced_string_type) => Display::fmt(advanced_string_type, f),
+            AdvancedType::Number(advanced_number_type) => Display::fmt(advanced_number_type, f),
             AdvancedType::AnyOf(advanced_enum_type) => Display::fmt(advanced_enum_type, f),
             AdvancedType::Tuple(tuple_type) => Display::fmt(tuple_type, f),
             AdvancedType::Array(array_type) => Display::fmt(array_type, f),
             AdvancedType::Object(object_type) => Display::fmt(object_type, f),
             AdvancedType::Optional(optional_type) => Display::fmt(optional_type, f),
+            AdvancedType::Const(const_type) => Display::fmt(const_type, f),
+            AdvancedType::Enum(enum_type) => Display::fmt(enum_type, f),
+            AdvancedType::Tagged(tagged_type) => Display::fmt(tagged_type, f),
+            AdvancedType::AllOf(all_of_type) => Display::fmt(all_of_type, f),
+            AdvancedType::OneOf(one_of_type) => Display::fmt(one_of_type, f),
+            AdvancedType::Not(not_type) => Display::fmt(not_type, f),
+            AdvancedType::Record(record_type) => Display::fmt(record_type, f),
+            AdvancedType::Ref(ref_type) => Display::fmt(ref_type, f),
+            AdvancedType::Custom(custom_type) => Display::fmt(custom_type, f),
+            AdvancedType::If(if_type) => Display::fmt(if_type, f),
         }
     }
 }
@@ -50,6 +100,9 @@ pub enum AdvancedTypeValidationError {
     #[error("{0}")]
     StringValidationError(#[from] StringValidationError),
 
+    #[error("{0}")]
+    NumberValidationError(#[from] NumberValidationError),
+
     #[error("{0}")]
     AnyOfError(#[from] AnyOfTypeError),
 
@@ -62,10 +115,77 @@ pub enum AdvancedTypeValidationError {
     #[error("{0}")]
     ObjectError(#[from] ObjectTypeError),
 
+    #[error("{0}")]
+    ConstError(#[from] ConstTypeError),
+
+    #[error("{0}")]
+    EnumError(#[from] EnumTypeError),
+
+    #[error("{0}")]
+    TaggedError(#[from] TaggedTypeError),
+
+    #[error("{0}")]
+    AllOfError(#[from] AllOfTypeError),
+
+    #[error("{0}")]
+    OneOfError(#[from] OneOfTypeError),
+
+    #[error("{0}")]
+    NotError(#[from] NotTypeError),
+
+    #[error("{0}")]
+    RecordError(#[from] RecordTypeError),
+
+    #[error("{0}")]
+    RefError(#[from] RefTypeError),
+
+    #[error("{0}")]
+    IfError(#[from] IfTypeError),
+
     #[error("{0}")]
     SchemaTypeValidationError(Box<SchemaTypeValidationError>),
 }
 
+impl ErrorCode for AdvancedTypeValidationError {
+    fn code(&self) -> &'static str {
+        self.inner().code()
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        self.inner().params()
+    }
+
+    fn custom_message(&self) -> Option<&str> {
+        self.inner().custom_message()
+    }
+}
+
+impl AdvancedTypeValidationError {
+    /// Returns the wrapped error, which provides the code, params and custom message.
+    fn inner(&self) -> &dyn ErrorCode {
+        match self {
+            AdvancedTypeValidationError::StringValidationError(error) => error,
+            AdvancedTypeValidationError::NumberValidationError(error) => error,
+            AdvancedTypeValidationError::AnyOfError(error) => error,
+            AdvancedTypeValidationError::TupleError(error) => error,
+            AdvancedTypeValidationError::ArrayError(error) => error,
+            AdvancedTypeValidationError::ObjectError(error) => error,
+            AdvancedTypeValidationError::ConstError(error) => error,
+            AdvancedTypeValidationError::EnumError(error) => error,
+            AdvancedTypeValidationError::TaggedError(error) => error,
+            AdvancedTypeValidationError::AllOfError(error) => error,
+            AdvancedTypeValidationError::OneOfError(error) => error,
+            AdvancedTypeValidationError::NotError(error) => error,
+            AdvancedTypeValidationError::RecordError(error) => error,
+            AdvancedTypeValidationError::RefError(error) => error,
+            AdvancedTypeValidationError::IfError(error) => error,
+            AdvancedTypeValidationError::SchemaTypeValidationError(error) => &**error,
+        }
+    }
+}
+
+serialize_error_code!(AdvancedTypeValidationError);
+
 impl From<SchemaTypeValidationError> for AdvancedTypeValidationError {
     fn from(value: SchemaTypeValidationError) -> Self {
         AdvancedTypeValidationError::SchemaTypeValidationError(Box::new(value))
@@ -75,14 +195,49 @@ impl From<SchemaTypeValidationError> for AdvancedTypeValidationError {
 impl Validator for AdvancedType {
     type E = AdvancedTypeValidationError;
 
-    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
         match self {
             AdvancedType::String(advanced_string) => Ok(advanced_string.validate(value)?),
-            AdvancedType::AnyOf(advanced_enum) => Ok(advanced_enum.validate(value)?),
-            AdvancedType::Tuple(fixed_array_type) => Ok(fixed_array_type.validate(value)?),
-            AdvancedType::Array(array_type) => Ok(array_type.validate(value)?),
-            AdvancedType::Object(object_type) => Ok(object_type.validate(value)?),
-            AdvancedType::Optional(optional_type) => Ok(optional_type.validate(value)?),
+            AdvancedType::Number(advanced_number) => Ok(advanced_number.validate(value)?),
+            AdvancedType::AnyOf(advanced_enum) => Ok(advanced_enum.validate_in(value, scope)?),
+            AdvancedType::Tuple(fixed_array_type) => Ok(fixed_array_type.validate_in(value, scope)?),
+            AdvancedType::Array(array_type) => Ok(array_type.validate_in(value, scope)?),
+            AdvancedType::Object(object_type) => Ok(object_type.validate_in(value, scope)?),
+            AdvancedType::Optional(optional_type) => Ok(optional_type.validate_in(value, scope)?),
+            AdvancedType::Const(const_type) => Ok(const_type.validate(value)?),
+            AdvancedType::Enum(enum_type) => Ok(enum_type.validate(value)?),
+            AdvancedType::Tagged(tagged_type) => Ok(tagged_type.validate_in(value, scope)?),
+            AdvancedType::AllOf(all_of_type) => Ok(all_of_type.validate_in(value, scope)?),
+            AdvancedType::OneOf(one_of_type) => Ok(one_of_type.validate_in(value, scope)?),
+            AdvancedType::Not(not_type) => Ok(not_type.validate_in(value, scope)?),
+            AdvancedType::Record(record_type) => Ok(record_type.validate_in(value, scope)?),
+            AdvancedType::Ref(ref_type) => Ok(ref_type.validate_in(value, scope)?),
+            AdvancedType::Custom(custom_type) => Ok(custom_type.validate(value)?),
+            AdvancedType::If(if_type) => Ok(if_type.validate_in(value, scope)?),
+        }
+    }
+}
+
+impl ReportingValidator for AdvancedType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        match self {
+            AdvancedType::String(advanced_string) => advanced_string.report(value, context),
+            AdvancedType::Number(advanced_number) => advanced_number.report(value, context),
+            AdvancedType::AnyOf(advanced_enum) => advanced_enum.report(value, context),
+            AdvancedType::Tuple(fixed_array_type) => fixed_array_type.report(value, context),
+            AdvancedType::Array(array_type) => array_type.report(value, context),
+            AdvancedType::Object(object_type) => object_type.report(value, context),
+            AdvancedType::Optional(optional_type) => optional_type.report(value, context),
+            AdvancedType::Const(const_type) => const_type.report(value, context),
+            AdvancedType::Enum(enum_type) => enum_type.report(value, context),
+            AdvancedType::Tagged(tagged_type) => tagged_type.report(value, context),
+            AdvancedType::AllOf(all_of_type) => all_of_type.report(value, context),
+            AdvancedType::OneOf(one_of_type) => one_of_type.report(value, context),
+            AdvancedType::Not(not_type) => not_type.report(value, context),
+            AdvancedType::Record(record_type) => record_type.report(value, context),
+            AdvancedType::Ref(ref_type) => ref_type.report(value, context),
+            AdvancedType::Custom(custom_type) => custom_type.report(value, context),
+            AdvancedType::If(if_type) => if_type.report(value, context),
         }
     }
 }
@@ -93,6 +248,12 @@ impl From<AdvancedStringType> for AdvancedType {
     }
 }
 
+impl From<AdvancedNumberType> for AdvancedType {
+    fn from(value: AdvancedNumberType) -> Self {
+        AdvancedType::Number(value)
+    }
+}
+
 impl From<AnyOfType> for AdvancedType {
     fn from(value: AnyOfType) -> Self {
         AdvancedType::AnyOf(value)
@@ -123,9 +284,70 @@ impl From<OptionalType> for AdvancedType {
     }
 }
 
+impl From<ConstType> for AdvancedType {
+    fn from(value: ConstType) -> Self {
+        AdvancedType::Const(value)
+    }
+}
+
+impl From<EnumType> for AdvancedType {
+    fn from(value: EnumType) -> Self {
+        AdvancedType::Enum(value)
+    }
+}
+
+impl From<TaggedType> for AdvancedType {
+    fn from(value: TaggedType) -> Self {
+        AdvancedType::Tagged(value)
+    }
+}
+
+impl From<AllOfType> for AdvancedType {
+    fn from(value: AllOfType) -> Self {
+        AdvancedType::AllOf(value)
+    }
+}
+
+impl From<OneOfType> for AdvancedType {
+    fn from(value: OneOfType) -> Self {
+        AdvancedType::OneOf(value)
+    }
+}
+
+impl From<NotType> for AdvancedType {
+    fn from(value: NotType) -> Self {
+        AdvancedType::Not(value)
+    }
+}
+
+impl From<RecordType> for AdvancedType {
+    fn from(value: RecordType) -> Self {
+        AdvancedType::Record(value)
+    }
+}
+
+impl From<RefType> for AdvancedType {
+    fn from(value: RefType) -> Self {
+        AdvancedType::Ref(value)
+    }
+}
+
+impl From<CustomType> for AdvancedType {
+    fn from(value: CustomType) -> Self {
+        AdvancedType::Custom(value)
+    }
+}
+
+impl From<IfType> for AdvancedType {
+    fn from(value: IfType) -> Self {
+        AdvancedType::If(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::schema_type::advanced_type::advanced_string_type::AdvancedStringType;
+    use crate::schema_type::advanced_type::advanced_string_type::{AdvancedStringType, LengthUnit};
+    use crate::schema_type::advanced_type::advanced_number_type::AdvancedNumberType;
     use crate::schema_type::advanced_type::any_of_type::AnyOfType;
     use crate::schema_type::advanced_type::optional_type::OptionalType;
     use crate::schema_type::advanced_type::AdvancedType;
@@ -133,6 +355,18 @@ mod tests {
     use crate::schema_type::SchemaType;
     use serde_json::json;
     use crate::schema_type::advanced_type::tuple_type::TupleType;
+    use crate::schema_type::pattern::Pattern;
+    use crate::schema_type::string_format::StringFormat;
+    use crate::schema_type::advanced_type::const_type::ConstType;
+    use crate::schema_type::advanced_type::enum_type::EnumType;
+    use crate::schema_type::advanced_type::tagged_type::TaggedType;
+    use std::collections::HashMap;
+    use crate::schema_type::advanced_type::all_of_type::AllOfType;
+    use crate::schema_type::advanced_type::one_of_type::OneOfType;
+    use crate::schema_type::advanced_type::not_type::NotType;
+    use crate::schema_type::advanced_type::record_type::{RecordKeyType, RecordType};
+    use crate::schema_type::advanced_type::ref_type::RefType;
+    use crate::schema_type::advanced_type::array_type::ArrayType;
 
     #[test]
     fn advanced_string_type_is_deserialized_correctly() {
@@ -141,6 +375,7 @@ mod tests {
             "requireFilled": false,
             "minLength": 10,
             "maxLength": 20,
+            "lengthUnit": "chars",
         }))
         .unwrap();
 
@@ -150,10 +385,79 @@ mod tests {
                 require_filled: false,
                 min_length: Some(10),
                 max_length: Some(20),
+                length_unit: LengthUnit::Chars,
+                pattern: None,
+                format: None,
             })
         );
     }
 
+    #[test]
+    fn advanced_string_type_with_pattern_is_deserialized_correctly() {
+        let advanced_type: AdvancedType = serde_json::from_value(json!({
+            "$": "string",
+            "pattern": "^SKU-[0-9]{6}$",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            advanced_type,
+            AdvancedStringType {
+                pattern: Some(Pattern::try_from("^SKU-[0-9]{6}$").unwrap()),
+                ..Default::default()
+            }.into()
+        );
+
+        let result = serde_json::from_value::<AdvancedType>(json!({
+            "$": "string",
+            "pattern": "(",
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn advanced_string_type_with_format_is_deserialized_correctly() {
+        let advanced_type: AdvancedType = serde_json::from_value(json!({
+            "$": "string",
+            "format": "dateTime",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            advanced_type,
+            AdvancedStringType {
+                format: Some(StringFormat::DateTime),
+                ..Default::default()
+            }.into()
+        );
+    }
+
+    #[test]
+    fn advanced_number_type_is_deserialized_correctly() {
+        let advanced_type: AdvancedType = serde_json::from_value(json!({
+            "$": "number",
+            "minimum": 1,
+            "maximum": 99,
+            "exclusiveMinimum": 0,
+            "exclusiveMaximum": 100,
+            "multipleOf": 1,
+            "integer": true,
+        }))
+        .unwrap();
+
+        assert_eq!(
+            advanced_type,
+            AdvancedNumberType {
+                minimum: Some(1_f64),
+                maximum: Some(99_f64),
+                exclusive_minimum: Some(0_f64),
+                exclusive_maximum: Some(100_f64),
+                multiple_of: Some(1_f64),
+                integer: true,
+            }.into()
+        );
+    }
+
     #[test]
     fn any_of_type_is_deserialized_correctly() {
         let advanced_type: AdvancedType = serde_json::from_value(json!({
@@ -194,6 +498,191 @@ mod tests {
                     BasicType::String.into(),
                     BasicType::Number.into(),
                 ],
+                rest: None,
+            }.into()
+        );
+    }
+
+    #[test]
+    fn const_type_is_deserialized_correctly() {
+        let advanced_type: AdvancedType = serde_json::from_value(json!({
+            "$": "const",
+            "value": "admin",
+        }))
+        .unwrap();
+
+        assert_eq!(advanced_type, ConstType::from(json!("admin")).into());
+    }
+
+    #[test]
+    fn enum_type_is_deserialized_correctly() {
+        let advanced_type: AdvancedType = serde_json::from_value(json!({
+            "$": "enum",
+            "values": ["draft", "published"],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            advanced_type,
+            EnumType::from([json!("draft"), json!("published")]).into()
+        );
+    }
+
+    #[test]
+    fn tagged_type_is_deserialized_correctly() {
+        let advanced_type: AdvancedType = serde_json::from_value(json!({
+            "$": "tagged",
+            "discriminator": "kind",
+            "variants": {
+                "circle": { "radius": "number" },
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            advanced_type,
+            TaggedType {
+                discriminator: "kind".to_string(),
+                variants: HashMap::from([
+                    ("circle".to_string(), HashMap::from([
+                        ("radius".to_string(), BasicType::Number.into()),
+                    ]).into()),
+                ]),
+            }.into()
+        );
+    }
+
+    #[test]
+    fn combinator_types_are_deserialized_correctly() {
+        let all_of: AdvancedType = serde_json::from_value(json!({
+            "$": "allOf",
+            "variants": ["string", "filledString"],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            all_of,
+            AllOfType::from([BasicType::String.into(), BasicType::FilledString.into()]).into()
+        );
+
+        let one_of: AdvancedType = serde_json::from_value(json!({
+            "$": "oneOf",
+            "variants": ["string", "number"],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            one_of,
+            OneOfType::from([BasicType::String.into(), BasicType::Number.into()]).into()
+        );
+
+        let not: AdvancedType = serde_json::from_value(json!({
+            "$": "not",
+            "type": "null",
+        }))
+        .unwrap();
+
+        assert_eq!(not, NotType::from(SchemaType::Basic(BasicType::Null)).into());
+        assert_eq!(not.to_string(), "not null");
+    }
+
+    #[test]
+    fn ref_type_is_deserialized_correctly() {
+        let advanced_type: AdvancedType = serde_json::from_value(json!({
+            "$": "ref",
+            "name": "comment",
+        }))
+        .unwrap();
+
+        assert_eq!(advanced_type, RefType::from("comment").into());
+    }
+
+    #[test]
+    fn record_type_is_deserialized_correctly() {
+        let advanced_type: AdvancedType = serde_json::from_value(json!({
+            "$": "record",
+            "values": "string",
+            "keys": "^[a-z]{2}$",
+            "minProperties": 1,
+            "maxProperties": 10,
+        }))
+        .unwrap();
+
+        assert_eq!(
+            advanced_type,
+            RecordType {
+                values: Box::new(BasicType::String.into()),
+                keys: Some(RecordKeyType::Pattern(Pattern::try_from("^[a-z]{2}$").unwrap())),
+                min_properties: Some(1),
+                max_properties: Some(10),
+            }.into()
+        );
+
+        let advanced_type: AdvancedType = serde_json::from_value(json!({
+            "$": "record",
+            "values": "string",
+            "keys": { "maxLength": 2 },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            advanced_type,
+            RecordType {
+                keys: Some(RecordKeyType::String(AdvancedStringType {
+                    max_length: Some(2),
+                    ..Default::default()
+                })),
+                ..RecordType::from(SchemaType::Basic(BasicType::String))
+            }.into()
+        );
+    }
+
+    #[test]
+    fn array_type_is_deserialized_correctly() {
+        let advanced_type: AdvancedType = serde_json::from_value(json!({
+            "$": "array",
+            "items": "object",
+            "requireFilled": false,
+            "minItems": 1,
+            "maxItems": 10,
+            "uniqueItems": true,
+            "uniqueBy": "/sku",
+            "contains": "object",
+            "minContains": 1,
+            "maxContains": 2,
+        }))
+        .unwrap();
+
+        assert_eq!(
+            advanced_type,
+            ArrayType {
+                require_filled: false,
+                items: Box::new(BasicType::Object.into()),
+                min_items: Some(1),
+                max_items: Some(10),
+                unique_items: true,
+                unique_by: Some("/sku".to_string()),
+                contains: Some(Box::new(BasicType::Object.into())),
+                min_contains: Some(1),
+                max_contains: Some(2),
+            }.into()
+        );
+    }
+
+    #[test]
+    fn tuple_type_with_rest_is_deserialized_correctly() {
+        let advanced_type: AdvancedType = serde_json::from_value(json!({
+            "$": "tuple",
+            "items": ["filledString"],
+            "rest": "string",
+        }))
+            .unwrap();
+
+        assert_eq!(
+            advanced_type,
+            TupleType {
+                items: vec![BasicType::FilledString.into()],
+                rest: Some(Box::new(BasicType::String.into())),
             }.into()
         );
     }
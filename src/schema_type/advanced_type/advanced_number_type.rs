@@ -0,0 +1,284 @@
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::Error as DeError;
+use serde_json::{json, Map, Number, Value};
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+
+#[derive(Debug, PartialEq, Error)]
+pub enum NumberValidationError {
+    #[error("The provided value is not a number")]
+    NotANumber,
+
+    #[error("Expected an integer, but got '{0}'")]
+    NotAnInteger(Number),
+
+    #[error("Expected a number greater than or equal to {1}, but got '{0}'")]
+    BelowMinimum(Number, f64),
+
+    #[error("Expected a number less than or equal to {1}, but got '{0}'")]
+    AboveMaximum(Number, f64),
+
+    #[error("Expected a number greater than {1}, but got '{0}'")]
+    BelowExclusiveMinimum(Number, f64),
+
+    #[error("Expected a number less than {1}, but got '{0}'")]
+    AboveExclusiveMaximum(Number, f64),
+
+    #[error("Expected a multiple of {1}, but got '{0}'")]
+    NotAMultipleOf(Number, f64),
+}
+
+impl ErrorCode for NumberValidationError {
+    fn code(&self) -> &'static str {
+        match self {
+            NumberValidationError::NotANumber => "number.notANumber",
+            NumberValidationError::NotAnInteger(_) => "number.notAnInteger",
+            NumberValidationError::BelowMinimum(_, _) => "number.belowMinimum",
+            NumberValidationError::AboveMaximum(_, _) => "number.aboveMaximum",
+            NumberValidationError::BelowExclusiveMinimum(_, _) => "number.belowExclusiveMinimum",
+            NumberValidationError::AboveExclusiveMaximum(_, _) => "number.aboveExclusiveMaximum",
+            NumberValidationError::NotAMultipleOf(_, _) => "number.notAMultipleOf",
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            NumberValidationError::NotANumber => Map::new(),
+            NumberValidationError::NotAnInteger(value) => to_params(json!({ "value": value })),
+            NumberValidationError::BelowMinimum(value, minimum) => {
+                to_params(json!({ "value": value, "minimum": minimum }))
+            }
+            NumberValidationError::AboveMaximum(value, maximum) => {
+                to_params(json!({ "value": value, "maximum": maximum }))
+            }
+            NumberValidationError::BelowExclusiveMinimum(value, minimum) => {
+                to_params(json!({ "value": value, "exclusiveMinimum": minimum }))
+            }
+            NumberValidationError::AboveExclusiveMaximum(value, maximum) => {
+                to_params(json!({ "value": value, "exclusiveMaximum": maximum }))
+            }
+            NumberValidationError::NotAMultipleOf(value, multiple_of) => {
+                to_params(json!({ "value": value, "multipleOf": multiple_of }))
+            }
+        }
+    }
+}
+
+serialize_error_code!(NumberValidationError);
+
+/// Number with additional constraints on the range and precision of the value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AdvancedNumberType {
+    /// If set, the number should be greater than or equal to this value.
+    pub minimum: Option<f64>,
+
+    /// If set, the number should be less than or equal to this value.
+    pub maximum: Option<f64>,
+
+    /// If set, the number should be greater than this value.
+    pub exclusive_minimum: Option<f64>,
+
+    /// If set, the number should be less than this value.
+    pub exclusive_maximum: Option<f64>,
+
+    /// If set, the number should be a multiple of this value. Schemas with a value of zero or less
+    /// are rejected when they are deserialized.
+    #[serde(default, deserialize_with = "deserialize_multiple_of")]
+    pub multiple_of: Option<f64>,
+
+    /// If this is set to true, the number cannot have a fractional part.
+    #[serde(default)]
+    pub integer: bool,
+}
+
+impl Display for AdvancedNumberType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.integer {
+            write!(f, "integer")
+        } else {
+            write!(f, "number")
+        }
+    }
+}
+
+fn deserialize_multiple_of<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    let multiple_of = Option::<f64>::deserialize(deserializer)?;
+
+    if let Some(multiple_of) = multiple_of.filter(|multiple_of| *multiple_of <= 0_f64) {
+        return Err(D::Error::custom(format!("multipleOf should be greater than zero, but got {}", multiple_of)));
+    }
+
+    Ok(multiple_of)
+}
+
+/// Floating point division is not exact, so the quotient is allowed to be off by a tiny amount.
+fn is_multiple_of(value: f64, multiple_of: f64) -> bool {
+    let quotient = value / multiple_of;
+
+    (quotient - quotient.round()).abs() < 1e-9
+}
+
+impl Validator for AdvancedNumberType {
+    type E = NumberValidationError;
+
+    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+        let Value::Number(number) = value else {
+            return Err(NumberValidationError::NotANumber);
+        };
+
+        let float = number.as_f64()
+            .ok_or(NumberValidationError::NotANumber)?;
+
+        if self.integer && !(number.is_i64() || number.is_u64() || float.fract() == 0_f64) {
+            return Err(NumberValidationError::NotAnInteger(number.clone()));
+        }
+
+        if let Some(minimum) = self.minimum {
+            if float < minimum {
+                return Err(NumberValidationError::BelowMinimum(number.clone(), minimum));
+            }
+        }
+
+        if let Some(maximum) = self.maximum {
+            if float > maximum {
+                return Err(NumberValidationError::AboveMaximum(number.clone(), maximum));
+            }
+        }
+
+        if let Some(exclusive_minimum) = self.exclusive_minimum {
+            if float <= exclusive_minimum {
+                return Err(NumberValidationError::BelowExclusiveMinimum(number.clone(), exclusive_minimum));
+            }
+        }
+
+        if let Some(exclusive_maximum) = self.exclusive_maximum {
+            if float >= exclusive_maximum {
+                return Err(NumberValidationError::AboveExclusiveMaximum(number.clone(), exclusive_maximum));
+            }
+        }
+
+        if let Some(multiple_of) = self.multiple_of {
+            if !is_multiple_of(float, multiple_of) {
+                return Err(NumberValidationError::NotAMultipleOf(number.clone(), multiple_of));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ReportingValidator for AdvancedNumberType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        context.report_result(self.validate(value).map_err(AdvancedTypeValidationError::from));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema_type::advanced_type::advanced_number_type::{
+        AdvancedNumberType, NumberValidationError,
+    };
+    use crate::traits::validator::Validator;
+    use serde_json::{json, Number};
+
+    #[test]
+    fn advanced_number_type_only_accepts_numbers() {
+        assert_eq!(AdvancedNumberType::default().validate(&json!(1.5)), Ok(()));
+        assert_eq!(
+            AdvancedNumberType::default().validate(&json!("1")),
+            Err(NumberValidationError::NotANumber)
+        );
+    }
+
+    #[test]
+    fn inclusive_range_is_checked_correctly() {
+        let number_type = AdvancedNumberType {
+            minimum: Some(1_f64),
+            maximum: Some(99_f64),
+            ..AdvancedNumberType::default()
+        };
+
+        assert_eq!(number_type.validate(&json!(1)), Ok(()));
+        assert_eq!(number_type.validate(&json!(99)), Ok(()));
+        assert_eq!(
+            number_type.validate(&json!(0)),
+            Err(NumberValidationError::BelowMinimum(Number::from(0), 1_f64))
+        );
+        assert_eq!(
+            number_type.validate(&json!(100)),
+            Err(NumberValidationError::AboveMaximum(Number::from(100), 99_f64))
+        );
+    }
+
+    #[test]
+    fn exclusive_range_is_checked_correctly() {
+        let number_type = AdvancedNumberType {
+            exclusive_minimum: Some(0_f64),
+            exclusive_maximum: Some(1_f64),
+            ..AdvancedNumberType::default()
+        };
+
+        assert_eq!(number_type.validate(&json!(0.5)), Ok(()));
+        assert_eq!(
+            number_type.validate(&json!(0)),
+            Err(NumberValidationError::BelowExclusiveMinimum(Number::from(0), 0_f64))
+        );
+        assert_eq!(
+            number_type.validate(&json!(1)),
+            Err(NumberValidationError::AboveExclusiveMaximum(Number::from(1), 1_f64))
+        );
+    }
+
+    #[test]
+    fn multiple_of_is_checked_correctly() {
+        let number_type = AdvancedNumberType {
+            multiple_of: Some(0.1),
+            ..AdvancedNumberType::default()
+        };
+
+        assert_eq!(number_type.validate(&json!(0.3)), Ok(()));
+        assert_eq!(number_type.validate(&json!(12)), Ok(()));
+        assert!(matches!(
+            number_type.validate(&json!(0.35)),
+            Err(NumberValidationError::NotAMultipleOf(_, _))
+        ));
+    }
+
+    #[test]
+    fn multiple_of_zero_or_less_is_rejected() {
+        let number_type: AdvancedNumberType = serde_json::from_value(json!({ "multipleOf": 0.5 })).unwrap();
+        assert_eq!(number_type.multiple_of, Some(0.5));
+
+        for multiple_of in [json!(0), json!(-2)] {
+            let error = serde_json::from_value::<AdvancedNumberType>(json!({ "multipleOf": multiple_of }))
+                .unwrap_err();
+
+            assert!(error.to_string().starts_with("multipleOf should be greater than zero"), "{}", error);
+        }
+
+        assert!(serde_json::from_value::<AdvancedNumberType>(json!({ "multipleOf": null })).is_ok());
+        assert!(serde_json::from_value::<AdvancedNumberType>(json!({})).is_ok());
+    }
+
+    #[test]
+    fn integer_is_checked_correctly() {
+        let number_type = AdvancedNumberType {
+            integer: true,
+            ..AdvancedNumberType::default()
+        };
+
+        assert_eq!(number_type.validate(&json!(10)), Ok(()));
+        assert_eq!(number_type.validate(&json!(-10)), Ok(()));
+        assert_eq!(number_type.validate(&json!(10.0)), Ok(()));
+        assert!(matches!(
+            number_type.validate(&json!(10.5)),
+            Err(NumberValidationError::NotAnInteger(_))
+        ));
+    }
+}
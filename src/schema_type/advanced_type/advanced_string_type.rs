@@ -1,9 +1,16 @@
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+use crate::traits::reporting_validator::ReportingValidator;
 use crate::traits::validator::Validator;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Map, Value};
 use std::fmt::{Display, Formatter};
 use thiserror::Error;
+use crate::schema_type::pattern::Pattern;
+use crate::schema_type::string_format::{StringFormat, StringFormatError};
 use crate::shared::default_true;
+use unicode_segmentation::UnicodeSegmentation;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
 
 #[derive(Debug, PartialEq, Error)]
 pub enum StringValidationError {
@@ -13,11 +20,74 @@ pub enum StringValidationError {
     #[error("The provided string is empty, but should be filled")]
     RequireFilled,
 
-    #[error("The provided string is too long")]
-    StringTooLong,
+    #[error("The provided string has a length of {0}, but should be at most {1}")]
+    StringTooLong(usize, usize),
 
-    #[error("The provided string is too short")]
-    StringTooShort,
+    #[error("The provided string has a length of {0}, but should be at least {1}")]
+    StringTooShort(usize, usize),
+
+    #[error("The provided string does not match the pattern '{0}'")]
+    PatternMismatch(String),
+
+    #[error(transparent)]
+    IncorrectFormat(#[from] StringFormatError),
+}
+
+impl ErrorCode for StringValidationError {
+    fn code(&self) -> &'static str {
+        match self {
+            StringValidationError::NotAString => "string.notAString",
+            StringValidationError::RequireFilled => "string.requireFilled",
+            StringValidationError::StringTooLong(_, _) => "string.tooLong",
+            StringValidationError::StringTooShort(_, _) => "string.tooShort",
+            StringValidationError::PatternMismatch(_) => "string.patternMismatch",
+            StringValidationError::IncorrectFormat(error) => error.code(),
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            StringValidationError::NotAString | StringValidationError::RequireFilled => Map::new(),
+            StringValidationError::StringTooLong(length, max) => {
+                to_params(json!({ "length": length, "max": max }))
+            }
+            StringValidationError::StringTooShort(length, min) => {
+                to_params(json!({ "length": length, "min": min }))
+            }
+            StringValidationError::PatternMismatch(pattern) => to_params(json!({ "pattern": pattern })),
+            StringValidationError::IncorrectFormat(error) => error.params(),
+        }
+    }
+}
+
+serialize_error_code!(StringValidationError);
+
+/// The unit used to measure the length of a string for [AdvancedStringType::min_length] and
+/// [AdvancedStringType::max_length].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LengthUnit {
+    /// The number of bytes in the UTF-8 encoded string.
+    Bytes,
+
+    /// The number of unicode scalar values, which is what most people think of as characters.
+    #[default]
+    Chars,
+
+    /// The number of extended grapheme clusters, so for example an emoji with a skin tone modifier
+    /// or a letter with a combining accent counts as a single character.
+    Graphemes,
+}
+
+impl LengthUnit {
+    /// Returns the length of the string measured in this unit.
+    pub fn length_of(&self, value: &str) -> usize {
+        match self {
+            LengthUnit::Bytes => value.len(),
+            LengthUnit::Chars => value.chars().count(),
+            LengthUnit::Graphemes => value.graphemes(true).count(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -32,6 +102,18 @@ pub struct AdvancedStringType {
 
     /// If set, ensures the string is less of equal to n characters long.
     pub max_length: Option<usize>,
+
+    /// The unit used for [AdvancedStringType::min_length] and [AdvancedStringType::max_length].
+    /// Defaults to [LengthUnit::Chars].
+    #[serde(default)]
+    pub length_unit: LengthUnit,
+
+    /// If set, ensures the string matches the given regular expression. The pattern is not
+    /// anchored, so use `^` and `$` to match against the whole string.
+    pub pattern: Option<Pattern>,
+
+    /// If set, ensures the string is formatted according to the given format.
+    pub format: Option<StringFormat>,
 }
 
 impl Display for AdvancedStringType {
@@ -40,7 +122,17 @@ impl Display for AdvancedStringType {
             write!(f, "filled ")?;
         }
 
-        write!(f, "string")
+        write!(f, "string")?;
+
+        if let Some(pattern) = &self.pattern {
+            write!(f, " matching '{}'", pattern)?;
+        }
+
+        if let Some(format) = &self.format {
+            write!(f, " formatted as {}", format)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -50,6 +142,9 @@ impl Default for AdvancedStringType {
             require_filled: true,
             min_length: None,
             max_length: None,
+            length_unit: LengthUnit::default(),
+            pattern: None,
+            format: None,
         }
     }
 }
@@ -62,31 +157,60 @@ impl Validator for AdvancedStringType {
             return Err(StringValidationError::NotAString);
         };
 
+        self.validate_str(string)
+    }
+}
+
+impl AdvancedStringType {
+    /// Checks the provided string, like [Validator::validate] does for string values.
+    pub(crate) fn validate_str(&self, string: &str) -> Result<(), StringValidationError> {
         if string.is_empty() && self.require_filled {
             return Err(StringValidationError::RequireFilled);
         }
 
-        if let Some(max_length) = self.max_length {
-            if string.len() > max_length {
-                return Err(StringValidationError::StringTooLong);
+        if self.max_length.is_some() || self.min_length.is_some() {
+            let length = self.length_unit.length_of(string);
+
+            if let Some(max_length) = self.max_length {
+                if length > max_length {
+                    return Err(StringValidationError::StringTooLong(length, max_length));
+                }
+            }
+
+            if let Some(min_length) = self.min_length {
+                if length < min_length {
+                    return Err(StringValidationError::StringTooShort(length, min_length));
+                }
             }
         }
 
-        if let Some(min_length) = self.min_length {
-            if string.len() < min_length {
-                return Err(StringValidationError::StringTooShort);
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(string) {
+                return Err(StringValidationError::PatternMismatch(pattern.to_string()));
             }
         }
 
+        if let Some(format) = &self.format {
+            format.validate_str(string)?;
+        }
+
         Ok(())
     }
 }
 
+impl ReportingValidator for AdvancedStringType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        context.report_result(self.validate(value).map_err(AdvancedTypeValidationError::from));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::schema_type::advanced_type::advanced_string_type::{
-        AdvancedStringType, StringValidationError,
+        AdvancedStringType, LengthUnit, StringValidationError,
     };
+    use crate::schema_type::pattern::Pattern;
+    use crate::schema_type::string_format::{StringFormat, StringFormatError};
     use crate::traits::validator::Validator;
     use serde_json::json;
 
@@ -155,7 +279,7 @@ mod tests {
                 ..AdvancedStringType::default()
             }
             .validate(&json!("abcd")),
-            Err(StringValidationError::StringTooShort)
+            Err(StringValidationError::StringTooShort(4, 5))
         );
     }
 
@@ -195,7 +319,102 @@ mod tests {
                 ..AdvancedStringType::default()
             }
             .validate(&json!("abcdef")),
-            Err(StringValidationError::StringTooLong)
+            Err(StringValidationError::StringTooLong(6, 5))
+        );
+    }
+
+    #[test]
+    fn advanced_string_type_pattern_is_checked_correctly() {
+        let advanced_string_type = AdvancedStringType {
+            pattern: Some(Pattern::try_from("^[a-z0-9]+(-[a-z0-9]+)*$").unwrap()),
+            ..AdvancedStringType::default()
+        };
+
+        assert_eq!(advanced_string_type.validate(&json!("my-first-post")), Ok(()));
+        assert_eq!(
+            advanced_string_type.validate(&json!("My First Post")),
+            Err(StringValidationError::PatternMismatch("^[a-z0-9]+(-[a-z0-9]+)*$".to_string()))
+        );
+    }
+
+    #[test]
+    fn advanced_string_type_format_is_checked_correctly() {
+        let advanced_string_type = AdvancedStringType {
+            format: Some(StringFormat::Date),
+            ..AdvancedStringType::default()
+        };
+
+        assert_eq!(advanced_string_type.validate(&json!("2023-10-12")), Ok(()));
+        assert_eq!(
+            advanced_string_type.validate(&json!("12-10-2023")),
+            Err(StringValidationError::IncorrectFormat(
+                StringFormatError::IncorrectDate("12-10-2023".to_string())
+            ))
+        );
+        assert_eq!(advanced_string_type.validate(&json!("")), Err(StringValidationError::RequireFilled));
+    }
+
+    #[test]
+    fn advanced_string_type_counts_characters_by_default() {
+        let advanced_string_type = AdvancedStringType {
+            max_length: Some(5),
+            ..AdvancedStringType::default()
+        };
+
+        assert_eq!(advanced_string_type.validate(&json!("こんにちは")), Ok(()));
+        assert_eq!(
+            advanced_string_type.validate(&json!("こんにちは!")),
+            Err(StringValidationError::StringTooLong(6, 5))
+        );
+    }
+
+    #[test]
+    fn advanced_string_type_length_unit_is_used() {
+        let bytes_type = AdvancedStringType {
+            max_length: Some(5),
+            length_unit: LengthUnit::Bytes,
+            ..AdvancedStringType::default()
+        };
+
+        assert_eq!(bytes_type.validate(&json!("hello")), Ok(()));
+        assert_eq!(
+            bytes_type.validate(&json!("héllo")),
+            Err(StringValidationError::StringTooLong(6, 5))
+        );
+
+        // A family emoji is made up of multiple characters joined together.
+        let family = "👨\u{200d}👩\u{200d}👧";
+        let graphemes_type = AdvancedStringType {
+            max_length: Some(1),
+            length_unit: LengthUnit::Graphemes,
+            ..AdvancedStringType::default()
+        };
+
+        assert_eq!(graphemes_type.validate(&json!(family)), Ok(()));
+        assert_eq!(
+            AdvancedStringType {
+                length_unit: LengthUnit::Chars,
+                ..graphemes_type.clone()
+            }
+            .validate(&json!(family)),
+            Err(StringValidationError::StringTooLong(5, 1))
+        );
+        assert_eq!(
+            AdvancedStringType {
+                min_length: Some(2),
+                max_length: None,
+                ..graphemes_type
+            }
+            .validate(&json!("e\u{301}")),
+            Err(StringValidationError::StringTooShort(1, 2))
+        );
+    }
+
+    #[test]
+    fn advanced_string_type_length_error_reports_lengths() {
+        assert_eq!(
+            StringValidationError::StringTooLong(12, 10).to_string(),
+            "The provided string has a length of 12, but should be at most 10"
         );
     }
 }
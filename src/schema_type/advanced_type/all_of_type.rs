@@ -0,0 +1,148 @@
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt::{Debug, Display, Formatter};
+use thiserror::Error;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+use crate::schema_type::advanced_type::object_type::SharedKeys;
+
+#[derive(Debug, PartialEq, Error)]
+#[error("Variant {0} did not match: {1}")]
+pub struct AllOfTypeError(pub usize, pub Box<SchemaTypeValidationError>);
+
+impl ErrorCode for AllOfTypeError {
+    fn code(&self) -> &'static str {
+        "allOf.variantMismatch"
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        to_params(json!({ "index": self.0, "error": self.1 }))
+    }
+}
+
+serialize_error_code!(AllOfTypeError);
+
+/// Passes if the provided value matches all of the provided type conditions. This can be used to
+/// mix shared fields into multiple object schemas.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllOfType {
+    pub variants: Vec<SchemaType>,
+}
+
+impl Display for AllOfType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let all_of = self
+            .variants
+            .iter()
+            .map(|schema| schema.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        write!(f, "all of: {}", all_of)
+    }
+}
+
+impl Validator for AllOfType {
+    type E = AllOfTypeError;
+
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
+        scope.share(SharedKeys::Declared(&self.variants), |scope| {
+            for (i, variant) in self.variants.iter().enumerate() {
+                variant.validate_in(value, scope)
+                    .map_err(|error| AllOfTypeError(i, Box::new(error)))?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl ReportingValidator for AllOfType {
+    /// Every variant applies to the value, so the errors of all variants are reported.
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        context.share(value, SharedKeys::Declared(&self.variants), |context| {
+            context.enter_schema("variants", |context| {
+                for (i, variant) in self.variants.iter().enumerate() {
+                    context.enter_schema(i, |context| variant.report(value, context));
+                }
+            });
+        });
+    }
+}
+
+impl<const U: usize> From<[SchemaType; U]> for AllOfType {
+    fn from(value: [SchemaType; U]) -> Self {
+        AllOfType {
+            variants: value.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema_type::advanced_type::all_of_type::{AllOfType, AllOfTypeError};
+    use crate::schema_type::advanced_type::object_type::ObjectTypeError;
+    use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+    use crate::schema_type::basic_type::BasicType;
+    use crate::schema_type::SchemaTypeValidationError;
+    use crate::traits::reporting_validator::ReportingValidator;
+    use crate::traits::validator::Validator;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn audited_user_type() -> AllOfType {
+        AllOfType::from([
+            HashMap::from([
+                ("createdAt".to_string(), BasicType::String.into()),
+                ("createdBy".to_string(), BasicType::Uuid.into()),
+            ]).into(),
+            HashMap::from([
+                ("name".to_string(), BasicType::String.into()),
+            ]).into(),
+        ])
+    }
+
+    #[test]
+    fn all_variants_must_match() {
+        let all_of_type = audited_user_type();
+
+        assert_eq!(all_of_type.validate(&json!({
+            "createdAt": "2023-10-01T12:00:00Z",
+            "createdBy": "f1df9904-6f6b-4157-8a82-b1a566a50ec2",
+            "name": "Alice",
+        })), Ok(()));
+
+        assert_eq!(
+            all_of_type.validate(&json!({
+                "createdAt": "2023-10-01T12:00:00Z",
+                "createdBy": "f1df9904-6f6b-4157-8a82-b1a566a50ec2",
+            })),
+            Err(AllOfTypeError(1, Box::new(
+                SchemaTypeValidationError::AdvancedTypeValidationError(
+                    AdvancedTypeValidationError::ObjectError(
+                        ObjectTypeError::MissingObjectKey("name".to_string())
+                    )
+                )
+            )))
+        );
+    }
+
+    #[test]
+    fn errors_of_all_variants_are_reported() {
+        let report = audited_user_type().validate_all(&json!({
+            "createdAt": "2023-10-01T12:00:00Z",
+        }));
+
+        let schema_paths = report.errors
+            .iter()
+            .map(|error| error.schema_path.to_json_pointer())
+            .collect::<Vec<String>>();
+
+        assert_eq!(schema_paths, vec!["/variants/0/createdBy", "/variants/1/name"]);
+    }
+}
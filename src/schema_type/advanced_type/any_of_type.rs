@@ -1,9 +1,14 @@
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
 use crate::schema_type::SchemaType;
+use crate::traits::reporting_validator::ReportingValidator;
 use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Map, Value};
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
 
 #[derive(Debug, PartialEq)]
 pub struct AnyOfTypeError(pub Vec<SchemaType>);
@@ -23,6 +28,23 @@ impl Display for AnyOfTypeError {
     }
 }
 
+impl ErrorCode for AnyOfTypeError {
+    fn code(&self) -> &'static str {
+        "anyOf.noMatch"
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        let variants = self.0
+            .iter()
+            .map(|schema| schema.to_string())
+            .collect::<Vec<String>>();
+
+        to_params(json!({ "variants": variants }))
+    }
+}
+
+serialize_error_code!(AnyOfTypeError);
+
 /// Passes if the provided value matches any of the provided type conditions.
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -46,9 +68,9 @@ impl Display for AnyOfType {
 impl Validator for AnyOfType {
     type E = AnyOfTypeError;
 
-    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
         for variant in &self.variants {
-            if let Ok(()) = variant.validate(value) {
+            if let Ok(()) = variant.validate_in(value, scope) {
                 return Ok(());
             }
         }
@@ -57,6 +79,15 @@ impl Validator for AnyOfType {
     }
 }
 
+impl ReportingValidator for AnyOfType {
+    /// Variants are still checked using the fail-fast validator, as only the errors of the variant
+    /// that ends up matching would be meaningful.
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        let result = self.validate_in(value, context.scope());
+        context.report_result(result.map_err(AdvancedTypeValidationError::from));
+    }
+}
+
 impl<const U: usize> From<[SchemaType; U]> for AnyOfType {
     fn from(value: [SchemaType; U]) -> Self {
         AnyOfType {
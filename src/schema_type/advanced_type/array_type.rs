@@ -1,10 +1,16 @@
 use std::fmt::{Display, Formatter};
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use std::ops::ControlFlow;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{json, Map, Value};
 use thiserror::Error;
 use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+use crate::traits::reporting_validator::ReportingValidator;
 use crate::traits::validator::Validator;
-use crate::shared::default_true;
+use crate::schema::validation_scope::ValidationScope;
+use crate::shared::{check_json_pointer, default_true, json_equals, json_pointer};
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
 
 #[derive(Debug, PartialEq, Error)]
 pub enum ArrayTypeError {
@@ -14,10 +20,70 @@ pub enum ArrayTypeError {
     #[error("The provided array is empty, but should contain at least one item")]
     RequireFilled,
 
+    #[error("Expected at least {1} items, but got {0}")]
+    TooFewItems(usize, usize),
+
+    #[error("Expected at most {1} items, but got {0}")]
+    TooManyItems(usize, usize),
+
+    #[error("The item at index {0} is a duplicate of the item at index {1}")]
+    DuplicateItem(usize, usize),
+
+    #[error("The item at index {0} has the same value for '{2}' as the item at index {1}")]
+    DuplicateKey(usize, usize, String),
+
+    #[error("Expected at least {1} items matching the contains schema, but got {0}")]
+    TooFewContains(usize, usize),
+
+    #[error("The item at index {0} exceeds the maximum of {1} items matching the contains schema")]
+    TooManyContains(usize, usize),
+
     #[error("{0}")]
     SchemaTypeValidationError(Box<SchemaTypeValidationError>),
 }
 
+impl ErrorCode for ArrayTypeError {
+    fn code(&self) -> &'static str {
+        match self {
+            ArrayTypeError::NotAnArray => "array.notAnArray",
+            ArrayTypeError::RequireFilled => "array.requireFilled",
+            ArrayTypeError::TooFewItems(_, _) => "array.tooFewItems",
+            ArrayTypeError::TooManyItems(_, _) => "array.tooManyItems",
+            ArrayTypeError::DuplicateItem(_, _) => "array.duplicateItem",
+            ArrayTypeError::DuplicateKey(_, _, _) => "array.duplicateKey",
+            ArrayTypeError::TooFewContains(_, _) => "array.tooFewContains",
+            ArrayTypeError::TooManyContains(_, _) => "array.tooManyContains",
+            ArrayTypeError::SchemaTypeValidationError(error) => error.code(),
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            ArrayTypeError::NotAnArray | ArrayTypeError::RequireFilled => Map::new(),
+            ArrayTypeError::TooFewItems(length, min) => to_params(json!({ "length": length, "min": min })),
+            ArrayTypeError::TooManyItems(length, max) => to_params(json!({ "length": length, "max": max })),
+            ArrayTypeError::DuplicateItem(index, duplicate_of) => {
+                to_params(json!({ "index": index, "duplicateOf": duplicate_of }))
+            }
+            ArrayTypeError::DuplicateKey(index, duplicate_of, key) => {
+                to_params(json!({ "index": index, "duplicateOf": duplicate_of, "key": key }))
+            }
+            ArrayTypeError::TooFewContains(count, min) => to_params(json!({ "count": count, "min": min })),
+            ArrayTypeError::TooManyContains(index, max) => to_params(json!({ "index": index, "max": max })),
+            ArrayTypeError::SchemaTypeValidationError(error) => error.params(),
+        }
+    }
+
+    fn custom_message(&self) -> Option<&str> {
+        match self {
+            ArrayTypeError::SchemaTypeValidationError(error) => error.custom_message(),
+            _ => None,
+        }
+    }
+}
+
+serialize_error_code!(ArrayTypeError);
+
 impl From<SchemaTypeValidationError> for ArrayTypeError {
     fn from(value: SchemaTypeValidationError) -> Self {
         ArrayTypeError::SchemaTypeValidationError(Box::new(value))
@@ -26,13 +92,168 @@ impl From<SchemaTypeValidationError> for ArrayTypeError {
 
 /// Checks for a variable length array that all match the given type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ArrayType {
-    /// If this is set to true, the array should have at least one item.
-    #[serde(default = "default_true")]
+    /// If this is set to true, the array should have at least one item. This is serialized as
+    /// `require_filled` for existing schemas, but `requireFilled` is also accepted like it is for
+    /// strings.
+    #[serde(default = "default_true", rename = "require_filled", alias = "requireFilled")]
     pub require_filled: bool,
 
     /// The shape that all items in the array should have.
     pub items: Box<SchemaType>,
+
+    /// If set, the array should contain at least n number of items.
+    pub min_items: Option<usize>,
+
+    /// If set, the array should contain at most n number of items.
+    pub max_items: Option<usize>,
+
+    /// If this is set to true, no two items in the array can be equal.
+    #[serde(default)]
+    pub unique_items: bool,
+
+    /// If set, no two items can have the same value at this JSON Pointer, for example `/sku`.
+    /// Items that don't have a value at the pointer are ignored. Paths that don't start with a `/`
+    /// are rejected when deserializing.
+    #[serde(default, deserialize_with = "deserialize_unique_by")]
+    pub unique_by: Option<String>,
+
+    /// If set, at least [ArrayType::min_contains] and at most [ArrayType::max_contains] items
+    /// should match this schema. The other items don't have to match it.
+    pub contains: Option<Box<SchemaType>>,
+
+    /// The minimum number of items that should match [ArrayType::contains]. Defaults to 1.
+    pub min_contains: Option<usize>,
+
+    /// The maximum number of items that can match [ArrayType::contains].
+    pub max_contains: Option<usize>,
+}
+
+fn deserialize_unique_by<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    let unique_by = Option::<String>::deserialize(deserializer)?;
+
+    if let Some(pointer) = &unique_by {
+        check_json_pointer(pointer)?;
+    }
+
+    Ok(unique_by)
+}
+
+impl ArrayType {
+    /// Returns the constraints that apply to the array as a whole.
+    fn constraints(&self) -> ArrayConstraints<'_> {
+        ArrayConstraints {
+            min_items: self.min_items,
+            max_items: self.max_items,
+            unique_items: self.unique_items,
+            unique_by: self.unique_by.as_deref(),
+            min_contains: self.min_contains.unwrap_or(1),
+            max_contains: self.max_contains,
+        }
+    }
+}
+
+/// The constraints of an [ArrayType] that apply to the array as a whole, which are checked after
+/// the items. These are shared with the [CompiledSchema], so both give the same errors in the
+/// same order.
+///
+/// [CompiledSchema]: crate::compiled_schema::CompiledSchema
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ArrayConstraints<'a> {
+    pub(crate) min_items: Option<usize>,
+    pub(crate) max_items: Option<usize>,
+    pub(crate) unique_items: bool,
+    pub(crate) unique_by: Option<&'a str>,
+    pub(crate) min_contains: usize,
+    pub(crate) max_contains: Option<usize>,
+}
+
+impl ArrayConstraints<'_> {
+    /// Checks the constraints against the items. `contains` tells whether an item matches
+    /// [ArrayType::contains], if it is set. Each error is passed to `on_error` together with the
+    /// index of the offending item if there is one, and checking stops once it breaks.
+    pub(crate) fn check<B>(
+        &self,
+        items: &[Value],
+        contains: Option<impl Fn(&Value) -> bool>,
+        mut on_error: impl FnMut(Option<usize>, ArrayTypeError) -> ControlFlow<B>,
+    ) -> ControlFlow<B> {
+        if let Some(min_items) = self.min_items {
+            if items.len() < min_items {
+                on_error(None, ArrayTypeError::TooFewItems(items.len(), min_items))?;
+            }
+        }
+
+        if let Some(max_items) = self.max_items {
+            if items.len() > max_items {
+                on_error(None, ArrayTypeError::TooManyItems(items.len(), max_items))?;
+            }
+        }
+
+        if self.unique_items {
+            for (i, item) in items.iter().enumerate() {
+                if let Some(first) = items[..i].iter().position(|previous| json_equals(previous, item)) {
+                    on_error(Some(i), ArrayTypeError::DuplicateItem(i, first))?;
+                }
+            }
+        }
+
+        if let Some(unique_by) = self.unique_by {
+            for (i, item) in items.iter().enumerate() {
+                let Some(key) = json_pointer(item, unique_by) else {
+                    continue;
+                };
+
+                let duplicate = items[..i].iter()
+                    .position(|previous| json_pointer(previous, unique_by).is_some_and(|previous| json_equals(previous, key)));
+
+                if let Some(first) = duplicate {
+                    on_error(Some(i), ArrayTypeError::DuplicateKey(i, first, unique_by.to_string()))?;
+                }
+            }
+        }
+
+        if let Some(contains) = contains {
+            let mut count = 0;
+
+            for (i, item) in items.iter().enumerate() {
+                if !contains(item) {
+                    continue;
+                }
+
+                count += 1;
+
+                if let Some(max_contains) = self.max_contains {
+                    if count == max_contains + 1 {
+                        on_error(Some(i), ArrayTypeError::TooManyContains(i, max_contains))?;
+                    }
+                }
+            }
+
+            if count < self.min_contains {
+                on_error(None, ArrayTypeError::TooFewContains(count, self.min_contains))?;
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl From<SchemaType> for ArrayType {
+    fn from(value: SchemaType) -> Self {
+        ArrayType {
+            require_filled: true,
+            items: Box::new(value),
+            min_items: None,
+            max_items: None,
+            unique_items: false,
+            unique_by: None,
+            contains: None,
+            min_contains: None,
+            max_contains: None,
+        }
+    }
 }
 
 impl Display for ArrayType {
@@ -47,7 +268,7 @@ impl Display for ArrayType {
 impl Validator for ArrayType {
     type E = ArrayTypeError;
 
-    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
         let Value::Array(items) = value else {
             return Err(ArrayTypeError::NotAnArray);
         };
@@ -57,26 +278,84 @@ impl Validator for ArrayType {
         }
 
         for item in items {
-            self.items.validate(item)?;
+            self.items.validate_in(item, scope.nested())?;
+        }
+
+        let contains = self.contains.as_ref()
+            .map(|contains| move |item: &Value| contains.validate_in(item, scope.nested()).is_ok());
+
+        if let ControlFlow::Break(error) = self.constraints().check(items, contains, |_, error| ControlFlow::Break(error)) {
+            return Err(error);
         }
 
         Ok(())
     }
 }
 
+impl ArrayType {
+    /// Reports the errors for every item in the array. This is shared with the
+    /// [SchemaType::Array] shorthand, which stores the item schema at a different location.
+    pub(crate) fn report_items(
+        schema: &SchemaType,
+        items: &[Value],
+        context: &mut ValidationContext,
+    ) {
+        for (i, item) in items.iter().enumerate() {
+            context.enter_instance(i, |context| schema.report(item, context));
+        }
+    }
+}
+
+impl ReportingValidator for ArrayType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        let Value::Array(items) = value else {
+            context.report(AdvancedTypeValidationError::ArrayError(ArrayTypeError::NotAnArray));
+            return;
+        };
+
+        if items.is_empty() && self.require_filled {
+            context.report(AdvancedTypeValidationError::ArrayError(ArrayTypeError::RequireFilled));
+        }
+
+        context.enter_schema("items", |context| {
+            ArrayType::report_items(&self.items, items, context);
+        });
+
+        let scope = context.scope();
+        let contains = self.contains.as_ref()
+            .map(|contains| move |item: &Value| contains.validate_in(item, scope.nested()).is_ok());
+
+        // The errors are collected first, as the scope borrows the context while checking.
+        let mut errors = vec![];
+        let _: ControlFlow<()> = self.constraints().check(items, contains, |index, error| {
+            errors.push((index, AdvancedTypeValidationError::ArrayError(error)));
+
+            ControlFlow::Continue(())
+        });
+
+        for (index, error) in errors {
+            match index {
+                Some(index) => context.enter_instance(index, |context| context.report(error)),
+                None => context.report(error),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
     use crate::schema_type::advanced_type::array_type::{ArrayType, ArrayTypeError};
     use crate::schema_type::basic_type::BasicType;
     use crate::schema_type::SchemaType;
+    use crate::traits::reporting_validator::ReportingValidator;
     use crate::traits::validator::Validator;
 
     #[test]
     fn array_type_is_resolved_correctly() {
         let array_type = ArrayType {
             require_filled: true,
-            items: Box::new(SchemaType::Basic(BasicType::String)),
+            ..ArrayType::from(SchemaType::Basic(BasicType::String))
         };
 
         assert_eq!(array_type.validate(&json!([
@@ -89,7 +368,7 @@ mod tests {
     fn require_filled_is_resolved_correctly() {
         let array_type = ArrayType {
             require_filled: true,
-            items: Box::new(SchemaType::Basic(BasicType::String)),
+            ..ArrayType::from(SchemaType::Basic(BasicType::String))
         };
 
         assert_eq!(array_type.validate(&json!([
@@ -103,7 +382,7 @@ mod tests {
     fn any_incorrect_type_returns_an_error() {
         let array_type = ArrayType {
             require_filled: true,
-            items: Box::new(SchemaType::Basic(BasicType::String)),
+            ..ArrayType::from(SchemaType::Basic(BasicType::String))
         };
 
         assert!(array_type.validate(&json!([
@@ -112,4 +391,135 @@ mod tests {
             10
         ])).is_err());
     }
+
+    #[test]
+    fn item_count_is_checked_correctly() {
+        let array_type = ArrayType {
+            min_items: Some(2),
+            max_items: Some(3),
+            ..ArrayType::from(SchemaType::Basic(BasicType::Number))
+        };
+
+        assert_eq!(array_type.validate(&json!([1])), Err(ArrayTypeError::TooFewItems(1, 2)));
+        assert_eq!(array_type.validate(&json!([1, 2])), Ok(()));
+        assert_eq!(array_type.validate(&json!([1, 2, 3])), Ok(()));
+        assert_eq!(array_type.validate(&json!([1, 2, 3, 4])), Err(ArrayTypeError::TooManyItems(4, 3)));
+    }
+
+    #[test]
+    fn unique_items_are_checked_correctly() {
+        let array_type = ArrayType {
+            unique_items: true,
+            ..ArrayType::from(SchemaType::Basic(BasicType::String))
+        };
+
+        assert_eq!(array_type.validate(&json!(["a", "b", "c"])), Ok(()));
+        assert_eq!(
+            array_type.validate(&json!(["a", "b", "a"])),
+            Err(ArrayTypeError::DuplicateItem(2, 0))
+        );
+
+        let number_array_type = ArrayType {
+            unique_items: true,
+            ..ArrayType::from(SchemaType::Basic(BasicType::Number))
+        };
+
+        assert_eq!(number_array_type.validate(&json!([9007199254740992_u64, 9007199254740993_u64])), Ok(()));
+        assert_eq!(number_array_type.validate(&json!([1, 1.0])), Err(ArrayTypeError::DuplicateItem(1, 0)));
+    }
+
+    #[test]
+    fn unique_by_is_checked_correctly() {
+        let array_type = ArrayType {
+            unique_by: Some("/sku".to_string()),
+            ..ArrayType::from(SchemaType::Basic(BasicType::Object))
+        };
+
+        assert_eq!(array_type.validate(&json!([
+            { "sku": "A-1", "quantity": 1 },
+            { "sku": "B-1", "quantity": 1 },
+            { "quantity": 1 },
+            { "quantity": 1 },
+        ])), Ok(()));
+
+        assert_eq!(
+            array_type.validate(&json!([
+                { "sku": "A-1", "quantity": 1 },
+                { "sku": "B-1", "quantity": 1 },
+                { "sku": "A-1", "quantity": 2 },
+            ])),
+            Err(ArrayTypeError::DuplicateKey(2, 0, "/sku".to_string()))
+        );
+    }
+
+    #[test]
+    fn unique_by_without_leading_slash_is_rejected() {
+        let array_type: ArrayType = serde_json::from_value(json!({ "items": "object", "uniqueBy": "/sku" })).unwrap();
+        assert_eq!(array_type.unique_by, Some("/sku".to_string()));
+
+        let error = serde_json::from_value::<ArrayType>(json!({ "items": "object", "uniqueBy": "sku" })).unwrap_err();
+        assert_eq!(error.to_string(), "'sku' is not a JSON Pointer, use '/sku' to refer to a key");
+    }
+
+    #[test]
+    fn require_filled_is_serialized_with_the_original_key() {
+        let array_type = ArrayType {
+            require_filled: false,
+            ..ArrayType::from(SchemaType::Basic(BasicType::String))
+        };
+
+        let serialized = serde_json::to_value(&array_type).unwrap();
+        assert_eq!(serialized["require_filled"], json!(false));
+        assert_eq!(serde_json::from_value::<ArrayType>(serialized).unwrap(), array_type);
+
+        let camel_case: ArrayType = serde_json::from_value(json!({ "items": "string", "requireFilled": false })).unwrap();
+        assert_eq!(camel_case, array_type);
+    }
+
+    #[test]
+    fn contains_is_checked_correctly() {
+        let array_type = ArrayType {
+            contains: Some(Box::new(SchemaType::Basic(BasicType::String))),
+            ..ArrayType::from(SchemaType::Basic(BasicType::Any))
+        };
+
+        assert_eq!(array_type.validate(&json!([1, "a", 2])), Ok(()));
+        assert_eq!(array_type.validate(&json!([1, 2])), Err(ArrayTypeError::TooFewContains(0, 1)));
+
+        let array_type = ArrayType {
+            contains: Some(Box::new(SchemaType::Basic(BasicType::String))),
+            min_contains: Some(2),
+            max_contains: Some(3),
+            ..ArrayType::from(SchemaType::Basic(BasicType::Any))
+        };
+
+        assert_eq!(array_type.validate(&json!(["a", 1])), Err(ArrayTypeError::TooFewContains(1, 2)));
+        assert_eq!(array_type.validate(&json!(["a", 1, "b", "c"])), Ok(()));
+        assert_eq!(
+            array_type.validate(&json!(["a", 1, "b", "c", 2, "d"])),
+            Err(ArrayTypeError::TooManyContains(5, 3))
+        );
+    }
+
+    #[test]
+    fn offending_items_are_reported_at_their_index() {
+        let array_type = ArrayType {
+            unique_items: true,
+            max_items: Some(3),
+            ..ArrayType::from(SchemaType::Basic(BasicType::String))
+        };
+
+        let report = array_type.validate_all(&json!(["a", "b", "a", "b"]));
+
+        let errors = report.errors
+            .iter()
+            .map(|error| (error.instance_path.to_json_pointer(), error.error.to_string()))
+            .collect::<Vec<(String, String)>>();
+
+        assert_eq!(errors, vec![
+            ("".to_string(), ArrayTypeError::TooManyItems(4, 3).to_string()),
+            ("/2".to_string(), ArrayTypeError::DuplicateItem(2, 0).to_string()),
+            ("/3".to_string(), ArrayTypeError::DuplicateItem(3, 1).to_string()),
+        ]);
+    }
 }
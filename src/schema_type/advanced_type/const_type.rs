@@ -0,0 +1,116 @@
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+use crate::shared::json_equals;
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+
+#[derive(Debug, PartialEq)]
+pub struct ConstTypeError(pub Value);
+
+impl Error for ConstTypeError {}
+
+impl Display for ConstTypeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Incorrect value. Expected: {}", self.0)
+    }
+}
+
+impl ErrorCode for ConstTypeError {
+    fn code(&self) -> &'static str {
+        "const.mismatch"
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        to_params(json!({ "expected": self.0 }))
+    }
+}
+
+serialize_error_code!(ConstTypeError);
+
+/// Passes if the provided value is exactly equal to the given value. Numbers are compared by their
+/// numeric value, so `1` and `1.0` are considered equal.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConstType {
+    pub value: Value,
+}
+
+impl Display for ConstType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "literal {}", self.value)
+    }
+}
+
+impl Validator for ConstType {
+    type E = ConstTypeError;
+
+    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+        if !json_equals(&self.value, value) {
+            return Err(ConstTypeError(self.value.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+impl ReportingValidator for ConstType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        context.report_result(self.validate(value).map_err(AdvancedTypeValidationError::from));
+    }
+}
+
+impl From<Value> for ConstType {
+    fn from(value: Value) -> Self {
+        ConstType {
+            value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema_type::advanced_type::const_type::{ConstType, ConstTypeError};
+    use crate::traits::validator::Validator;
+    use serde_json::json;
+
+    #[test]
+    fn const_string_is_checked_correctly() {
+        let const_type = ConstType::from(json!("admin"));
+
+        assert_eq!(const_type.validate(&json!("admin")), Ok(()));
+        assert_eq!(
+            const_type.validate(&json!("user")),
+            Err(ConstTypeError(json!("admin")))
+        );
+        assert!(const_type.validate(&json!(null)).is_err());
+    }
+
+    #[test]
+    fn const_numbers_are_compared_by_value() {
+        let const_type = ConstType::from(json!(1));
+
+        assert_eq!(const_type.validate(&json!(1)), Ok(()));
+        assert_eq!(const_type.validate(&json!(1.0)), Ok(()));
+        assert!(const_type.validate(&json!(1.5)).is_err());
+        assert!(const_type.validate(&json!("1")).is_err());
+
+        let large_const_type = ConstType::from(json!(9007199254740993_u64));
+
+        assert_eq!(large_const_type.validate(&json!(9007199254740993_u64)), Ok(()));
+        assert!(large_const_type.validate(&json!(9007199254740992_u64)).is_err());
+    }
+
+    #[test]
+    fn const_objects_are_compared_deeply() {
+        let const_type = ConstType::from(json!({ "role": "admin", "level": [1, 2] }));
+
+        assert_eq!(const_type.validate(&json!({ "level": [1, 2], "role": "admin" })), Ok(()));
+        assert!(const_type.validate(&json!({ "role": "admin", "level": [2, 1] })).is_err());
+        assert!(const_type.validate(&json!({ "role": "admin" })).is_err());
+    }
+}
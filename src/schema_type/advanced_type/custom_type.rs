@@ -0,0 +1,238 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::Arc;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Map, Value};
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::SchemaTypeValidationError;
+use crate::traits::custom_validator::CustomValidator;
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+
+/// Error returned by a [CustomValidator]. As the error can be of any type, two errors are
+/// considered equal if their messages are equal. Use [CustomValidationError::downcast_ref] to get
+/// the original error.
+#[derive(Debug)]
+pub struct CustomValidationError(pub Box<dyn Error + Send + Sync>);
+
+impl CustomValidationError {
+    pub fn downcast_ref<T: Error + 'static>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+impl Error for CustomValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+impl Display for CustomValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl PartialEq for CustomValidationError {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl ErrorCode for CustomValidationError {
+    fn code(&self) -> &'static str {
+        "custom.invalid"
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        to_params(json!({ "message": self.0.to_string() }))
+    }
+}
+
+serialize_error_code!(CustomValidationError);
+
+/// Runs a [CustomValidator] that has been registered using `#[typetag::serde]`. Two custom types
+/// are considered equal if they serialize to the same value.
+#[derive(Debug, Clone)]
+pub struct CustomType(pub Arc<dyn CustomValidator>);
+
+impl Display for CustomType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.description())
+    }
+}
+
+impl PartialEq for CustomType {
+    fn eq(&self, other: &Self) -> bool {
+        match (serde_json::to_value(self), serde_json::to_value(other)) {
+            (Ok(left), Ok(right)) => left == right,
+            _ => false,
+        }
+    }
+}
+
+impl Serialize for CustomType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let validator = Box::<dyn CustomValidator>::deserialize(deserializer)?;
+
+        Ok(CustomType(Arc::from(validator)))
+    }
+}
+
+impl Validator for CustomType {
+    type E = SchemaTypeValidationError;
+
+    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+        self.0.validate(value)
+            .map_err(|error| CustomValidationError(error).into())
+    }
+}
+
+impl ReportingValidator for CustomType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        context.report_result(self.validate(value));
+    }
+}
+
+impl<T: CustomValidator + 'static> From<T> for CustomType {
+    fn from(value: T) -> Self {
+        CustomType(Arc::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use serde::{Deserialize, Serialize};
+    use serde_json::{json, Value};
+    use thiserror::Error;
+    use crate::schema_type::advanced_type::custom_type::{CustomType, CustomValidationError};
+    use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+    use crate::traits::custom_validator::CustomValidator;
+    use crate::traits::reporting_validator::ReportingValidator;
+    use crate::traits::validator::Validator;
+
+    #[derive(Debug, PartialEq, Error)]
+    #[error("Invalid IBAN: '{0}'")]
+    struct InvalidIban(String);
+
+    /// Checks the length and the check digits of an IBAN.
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Iban;
+
+    #[typetag::serde(name = "iban")]
+    impl CustomValidator for Iban {
+        fn validate(&self, value: &Value) -> Result<(), Box<dyn Error + Send + Sync>> {
+            let iban = value.as_str()
+                .ok_or("Expected an IBAN string")?
+                .replace(' ', "");
+
+            if iban.len() < 15 || iban.len() > 34 || !iban.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(InvalidIban(iban).into());
+            }
+
+            let remainder = iban[4..].chars()
+                .chain(iban[..4].chars())
+                .map(|c| c.to_digit(36).unwrap())
+                .fold(0, |remainder, digit| {
+                    let factor = if digit < 10 { 10 } else { 100 };
+                    (remainder * factor + digit) % 97
+                });
+
+            if remainder != 1 {
+                return Err(InvalidIban(iban).into());
+            }
+
+            Ok(())
+        }
+
+        fn description(&self) -> String {
+            "IBAN".to_string()
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct SkuPrefix {
+        prefix: String,
+    }
+
+    #[typetag::serde(name = "skuPrefix")]
+    impl CustomValidator for SkuPrefix {
+        fn validate(&self, value: &Value) -> Result<(), Box<dyn Error + Send + Sync>> {
+            match value.as_str() {
+                Some(sku) if sku.starts_with(&self.prefix) => Ok(()),
+                _ => Err(format!("Expected a SKU starting with '{}'", self.prefix).into()),
+            }
+        }
+    }
+
+    #[test]
+    fn custom_validator_is_run() {
+        let custom_type = CustomType::from(Iban);
+
+        assert_eq!(custom_type.validate(&json!("NL91 ABNA 0417 1643 00")), Ok(()));
+        assert_eq!(
+            custom_type.validate(&json!("NL91 ABNA 0417 1643 01")),
+            Err(SchemaTypeValidationError::CustomValidationError(CustomValidationError(
+                Box::new(InvalidIban("NL91ABNA0417164301".to_string()))
+            )))
+        );
+
+        let Err(SchemaTypeValidationError::CustomValidationError(error)) = custom_type.validate(&json!(10)) else {
+            panic!("Expected a custom validation error");
+        };
+
+        assert_eq!(error.to_string(), "Expected an IBAN string");
+        assert!(error.downcast_ref::<InvalidIban>().is_none());
+    }
+
+    #[test]
+    fn custom_type_is_deserialized_through_the_schema() {
+        let schema_type: SchemaType = serde_json::from_value(json!({
+            "iban": { "$": "custom", "validator": "iban" },
+            "sku": { "$": "custom", "validator": "skuPrefix", "prefix": "SKU-" },
+        }))
+            .unwrap();
+
+        assert_eq!(schema_type.validate(&json!({
+            "iban": "NL91ABNA0417164300",
+            "sku": "SKU-123",
+        })), Ok(()));
+
+        let report = schema_type.validate_all(&json!({
+            "iban": "NL00ABNA0417164300",
+            "sku": "123",
+        }));
+
+        let mut messages = report.errors
+            .iter()
+            .map(|error| format!("{}: {}", error.instance_path, error.error))
+            .collect::<Vec<String>>();
+
+        messages.sort();
+
+        assert_eq!(messages, vec![
+            "/iban: Invalid IBAN: 'NL00ABNA0417164300'",
+            "/sku: Expected a SKU starting with 'SKU-'",
+        ]);
+    }
+
+    #[test]
+    fn custom_type_is_serialized_with_its_fields() {
+        let schema_type = SchemaType::from(CustomType::from(SkuPrefix { prefix: "SKU-".to_string() }));
+
+        assert_eq!(
+            serde_json::to_value(&schema_type).unwrap(),
+            json!({ "$": "custom", "validator": "skuPrefix", "prefix": "SKU-" })
+        );
+        assert_eq!(schema_type.to_string(), "custom validator");
+        assert_eq!(SchemaType::from(CustomType::from(Iban)).to_string(), "IBAN");
+    }
+}
@@ -0,0 +1,118 @@
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+use crate::shared::json_equals;
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+
+#[derive(Debug, PartialEq)]
+pub struct EnumTypeError(pub Vec<Value>);
+
+impl Error for EnumTypeError {}
+
+impl Display for EnumTypeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let one_of = self
+            .0
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        write!(f, "No matching value. Expected one of: {}", one_of)
+    }
+}
+
+impl ErrorCode for EnumTypeError {
+    fn code(&self) -> &'static str {
+        "enum.noMatch"
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        to_params(json!({ "values": self.0 }))
+    }
+}
+
+serialize_error_code!(EnumTypeError);
+
+/// Passes if the provided value is equal to any of the given values. Unlike [AnyOfType], this
+/// compares against values instead of checking against types.
+///
+/// [AnyOfType]: crate::schema_type::advanced_type::any_of_type::AnyOfType
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnumType {
+    pub values: Vec<Value>,
+}
+
+impl Display for EnumType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let one_of = self
+            .values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        write!(f, "one of: {}", one_of)
+    }
+}
+
+impl Validator for EnumType {
+    type E = EnumTypeError;
+
+    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+        if self.values.iter().any(|allowed| json_equals(allowed, value)) {
+            return Ok(());
+        }
+
+        Err(EnumTypeError(self.values.to_vec()))
+    }
+}
+
+impl ReportingValidator for EnumType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        context.report_result(self.validate(value).map_err(AdvancedTypeValidationError::from));
+    }
+}
+
+impl<const U: usize> From<[Value; U]> for EnumType {
+    fn from(value: [Value; U]) -> Self {
+        EnumType {
+            values: value.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema_type::advanced_type::enum_type::{EnumType, EnumTypeError};
+    use crate::traits::validator::Validator;
+    use serde_json::json;
+
+    #[test]
+    fn enum_values_are_checked_correctly() {
+        let enum_type = EnumType::from([json!("draft"), json!("published")]);
+
+        assert_eq!(enum_type.validate(&json!("draft")), Ok(()));
+        assert_eq!(enum_type.validate(&json!("published")), Ok(()));
+        assert_eq!(
+            enum_type.validate(&json!("archived")),
+            Err(EnumTypeError(vec![json!("draft"), json!("published")]))
+        );
+    }
+
+    #[test]
+    fn enum_error_lists_the_allowed_values() {
+        let enum_type = EnumType::from([json!("draft"), json!(1), json!(null)]);
+
+        assert_eq!(
+            enum_type.validate(&json!(true)).unwrap_err().to_string(),
+            "No matching value. Expected one of: \"draft\", 1, null"
+        );
+    }
+}
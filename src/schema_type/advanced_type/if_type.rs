@@ -0,0 +1,243 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::{AdvancedType, AdvancedTypeValidationError};
+use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+use crate::schema_type::advanced_type::object_type::SharedKeys;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum IfTypeError {
+    #[error("When {0}: {1}")]
+    ThenError(String, Box<SchemaTypeValidationError>),
+
+    #[error("Unless {0}: {1}")]
+    ElseError(String, Box<SchemaTypeValidationError>),
+}
+
+impl ErrorCode for IfTypeError {
+    fn code(&self) -> &'static str {
+        match self {
+            IfTypeError::ThenError(_, _) => "if.then",
+            IfTypeError::ElseError(_, _) => "if.else",
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        let (IfTypeError::ThenError(condition, error) | IfTypeError::ElseError(condition, error)) = self;
+
+        to_params(json!({ "condition": condition, "error": error }))
+    }
+}
+
+serialize_error_code!(IfTypeError);
+
+/// Applies a schema depending on whether the value matches a condition. When the value matches
+/// [IfType::condition] it should also match [IfType::then], otherwise it should match
+/// [IfType::otherwise]. The condition itself never results in an error.
+///
+/// Errors from either branch include a description of the condition, so for a condition like
+/// `{ "country": { "$": "const", "value": "US" } }` a missing key is reported as
+/// `When 'country' is "US": Missing object key: 'state'`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IfType {
+    /// The schema that decides which branch is applied.
+    #[serde(rename = "if")]
+    pub condition: Box<SchemaType>,
+
+    /// The schema to apply when the value matches the condition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub then: Option<Box<SchemaType>>,
+
+    /// The schema to apply when the value does not match the condition.
+    #[serde(rename = "else", default, skip_serializing_if = "Option::is_none")]
+    pub otherwise: Option<Box<SchemaType>>,
+}
+
+impl IfType {
+    /// Describes the condition for use in error messages. Conditions on the keys of an object are
+    /// described per key, any other condition is described by its type.
+    pub fn describe_condition(&self) -> String {
+        let object = match (&*self.condition, self.condition.as_advanced()) {
+            (SchemaType::Object(object), _) => object,
+            (_, Some(AdvancedType::Object(object_type))) => &object_type.object,
+            (condition, _) => return format!("the value is {}", condition),
+        };
+
+        let mut keys = object.keys().collect::<Vec<&String>>();
+        keys.sort();
+
+        keys.into_iter()
+            .map(|key| match object[key].as_advanced() {
+                Some(AdvancedType::Const(const_type)) => format!("'{}' is {}", key, const_type.value),
+                _ => format!("'{}' is {}", key, object[key]),
+            })
+            .collect::<Vec<String>>()
+            .join(" and ")
+    }
+
+    /// Returns whether the value matches the condition. The condition and the branches only
+    /// describe some keys of an object, so the keys declared by either of them are shared.
+    pub(crate) fn matches(&self, value: &Value, scope: ValidationScope) -> bool {
+        scope.share(SharedKeys::If(self), |scope| self.condition.validate_in(value, scope).is_ok())
+    }
+
+    /// Returns whether the condition or one of the branches declares the key, see
+    /// [SchemaType::declares_key].
+    pub(crate) fn declares_key(&self, key: &str, target_map: &Map<String, Value>, scope: ValidationScope) -> bool {
+        std::iter::once(&*self.condition)
+            .chain(self.then.as_deref())
+            .chain(self.otherwise.as_deref())
+            .any(|schema| schema.declares_key(key, target_map, scope))
+    }
+
+    /// Returns the branch that applies to the value, together with whether the condition matched.
+    pub(crate) fn branch_for(&self, value: &Value, scope: ValidationScope) -> (Option<&SchemaType>, bool) {
+        if self.matches(value, scope) {
+            return (self.then.as_deref(), true);
+        }
+
+        (self.otherwise.as_deref(), false)
+    }
+
+    fn branch_error(&self, matched: bool, error: SchemaTypeValidationError) -> IfTypeError {
+        if matched {
+            return IfTypeError::ThenError(self.describe_condition(), Box::new(error));
+        }
+
+        IfTypeError::ElseError(self.describe_condition(), Box::new(error))
+    }
+}
+
+impl Display for IfType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "conditional on {}", self.condition)
+    }
+}
+
+impl Validator for IfType {
+    type E = IfTypeError;
+
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
+        let (branch, matched) = self.branch_for(value, scope);
+
+        let Some(branch) = branch else {
+            return Ok(());
+        };
+
+        scope.share(SharedKeys::If(self), |scope| {
+            branch.validate_in(value, scope)
+                .map_err(|error| self.branch_error(matched, error))
+        })
+    }
+}
+
+impl ReportingValidator for IfType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        let (branch, matched) = self.branch_for(value, context.scope());
+
+        let Some(branch) = branch else {
+            return;
+        };
+
+        let segment = if matched { "then" } else { "else" };
+
+        context.enter_schema(segment, |context| {
+            context.share(value, SharedKeys::If(self), |context| {
+                context.wrap_errors(
+                    |context| branch.report(value, context),
+                    |error| AdvancedTypeValidationError::IfError(self.branch_error(matched, error)).into(),
+                );
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use crate::schema_type::advanced_type::if_type::{IfType, IfTypeError};
+    use crate::schema_type::advanced_type::object_type::ObjectTypeError;
+    use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+    use crate::schema_type::SchemaTypeValidationError;
+    use crate::traits::reporting_validator::ReportingValidator;
+    use crate::traits::validator::Validator;
+
+    fn parse_if_type(value: Value) -> IfType {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn address_type() -> IfType {
+        parse_if_type(json!({
+            "if": { "country": { "$": "const", "value": "US" } },
+            "then": { "state": "filledString" },
+            "else": { "postalCode": "filledString" },
+        }))
+    }
+
+    #[test]
+    fn branch_is_picked_by_the_condition() {
+        let if_type = address_type();
+
+        assert_eq!(if_type.validate(&json!({ "country": "US", "state": "CA" })), Ok(()));
+        assert_eq!(if_type.validate(&json!({ "country": "NL", "postalCode": "1234 AB" })), Ok(()));
+
+        assert_eq!(
+            if_type.validate(&json!({ "country": "US", "postalCode": "90210" })),
+            Err(IfTypeError::ThenError(
+                "'country' is \"US\"".to_string(),
+                Box::new(SchemaTypeValidationError::AdvancedTypeValidationError(
+                    AdvancedTypeValidationError::ObjectError(ObjectTypeError::MissingObjectKey("state".to_string()))
+                )),
+            ))
+        );
+
+        assert_eq!(
+            if_type.validate(&json!({ "country": "NL" })).unwrap_err().to_string(),
+            "Unless 'country' is \"US\": Missing object key: 'postalCode'"
+        );
+    }
+
+    #[test]
+    fn missing_branches_pass() {
+        let if_type = parse_if_type(json!({
+            "if": "string",
+            "then": "filledString",
+        }));
+
+        assert_eq!(if_type.validate(&json!(10)), Ok(()));
+        assert_eq!(
+            if_type.validate(&json!("")).unwrap_err().to_string(),
+            "When the value is string: Expected a filled string, but got an empty string"
+        );
+    }
+
+    #[test]
+    fn errors_are_reported_with_the_condition() {
+        let if_type = parse_if_type(json!({
+            "if": { "paymentMethod": { "$": "const", "value": "card" } },
+            "then": {
+                "cardNumber": { "$": "string", "pattern": "^[0-9]{16}$" },
+                "cvc": { "$": "string", "pattern": "^[0-9]{3}$" },
+            },
+        }));
+
+        let report = if_type.validate_all(&json!({
+            "paymentMethod": "card",
+            "cardNumber": "1234",
+            "cvc": "12",
+        }));
+
+        assert_eq!(report.errors.len(), 2);
+        assert_eq!(report.errors[0].instance_path.to_json_pointer(), "/cardNumber");
+        assert_eq!(report.errors[0].schema_path.to_json_pointer(), "/then/cardNumber");
+        assert!(report.errors[0].error.to_string().starts_with("When 'paymentMethod' is \"card\": "));
+        assert_eq!(report.errors[1].instance_path.to_json_pointer(), "/cvc");
+    }
+}
@@ -0,0 +1,90 @@
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+use crate::schema_type::SchemaType;
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt::{Debug, Display, Formatter};
+use thiserror::Error;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+
+#[derive(Debug, PartialEq, Error)]
+#[error("Expected the value to not match: {0}")]
+pub struct NotTypeError(pub SchemaType);
+
+impl ErrorCode for NotTypeError {
+    fn code(&self) -> &'static str {
+        "not.matches"
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        to_params(json!({ "schema": self.0.to_string() }))
+    }
+}
+
+serialize_error_code!(NotTypeError);
+
+/// Passes if the provided value does not match the given type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotType {
+    /// The type the value should not match.
+    #[serde(rename = "type")]
+    pub kind: Box<SchemaType>,
+}
+
+impl Display for NotType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not ")?;
+        Display::fmt(&self.kind, f)
+    }
+}
+
+impl Validator for NotType {
+    type E = NotTypeError;
+
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
+        if self.kind.validate_in(value, scope).is_ok() {
+            return Err(NotTypeError(*self.kind.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+impl ReportingValidator for NotType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        let result = self.validate_in(value, context.scope());
+        context.report_result(result.map_err(AdvancedTypeValidationError::from));
+    }
+}
+
+impl From<SchemaType> for NotType {
+    fn from(value: SchemaType) -> Self {
+        NotType {
+            kind: Box::new(value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema_type::advanced_type::not_type::{NotType, NotTypeError};
+    use crate::schema_type::basic_type::BasicType;
+    use crate::schema_type::SchemaType;
+    use crate::traits::validator::Validator;
+    use serde_json::json;
+
+    #[test]
+    fn not_type_is_resolved_correctly() {
+        let not_type = NotType::from(SchemaType::Basic(BasicType::Null));
+
+        assert_eq!(not_type.validate(&json!("")), Ok(()));
+        assert_eq!(not_type.validate(&json!(10)), Ok(()));
+        assert_eq!(
+            not_type.validate(&json!(null)),
+            Err(NotTypeError(SchemaType::Basic(BasicType::Null)))
+        );
+    }
+}
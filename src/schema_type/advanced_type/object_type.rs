@@ -1,11 +1,20 @@
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Map, Value};
 use thiserror::Error;
 use crate::schema_type::advanced_type::AdvancedType;
+use crate::schema_type::advanced_type::if_type::IfType;
 use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+use crate::traits::reporting_validator::ReportingValidator;
 use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
+use crate::schema_type::advanced_type::object_type::object_constraint::{ObjectConstraint, ObjectConstraintError};
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+
+pub mod object_constraint;
 
 #[derive(Debug, PartialEq, Error)]
 pub enum ObjectTypeError {
@@ -15,21 +24,211 @@ pub enum ObjectTypeError {
     #[error("Missing object key: '{0}'")]
     MissingObjectKey(String),
 
+    #[error("Unexpected object key: '{0}'")]
+    UnexpectedKey(String),
+
+    #[error("Missing object key: '{0}', which is required when '{1}' is present")]
+    MissingDependentKey(String, String),
+
+    #[error("When '{0}' is present: {1}")]
+    DependentSchemaError(String, Box<SchemaTypeValidationError>),
+
+    #[error("{0}")]
+    ConstraintError(#[from] ObjectConstraintError),
+
     #[error("{0}")]
     SchemaTypeValidationError(Box<SchemaTypeValidationError>),
 }
 
+impl ErrorCode for ObjectTypeError {
+    fn code(&self) -> &'static str {
+        match self {
+            ObjectTypeError::NotAnObject => "object.notAnObject",
+            ObjectTypeError::MissingObjectKey(_) => "object.missingKey",
+            ObjectTypeError::UnexpectedKey(_) => "object.unexpectedKey",
+            ObjectTypeError::MissingDependentKey(_, _) => "object.missingDependentKey",
+            ObjectTypeError::DependentSchemaError(_, _) => "object.dependentSchema",
+            ObjectTypeError::ConstraintError(error) => error.code(),
+            ObjectTypeError::SchemaTypeValidationError(error) => error.code(),
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            ObjectTypeError::NotAnObject => Map::new(),
+            ObjectTypeError::MissingObjectKey(key)
+            | ObjectTypeError::UnexpectedKey(key) => to_params(json!({ "key": key })),
+            ObjectTypeError::MissingDependentKey(key, trigger) => {
+                to_params(json!({ "key": key, "trigger": trigger }))
+            }
+            ObjectTypeError::DependentSchemaError(trigger, error) => {
+                to_params(json!({ "trigger": trigger, "error": error }))
+            }
+            ObjectTypeError::ConstraintError(error) => error.params(),
+            ObjectTypeError::SchemaTypeValidationError(error) => error.params(),
+        }
+    }
+
+    fn custom_message(&self) -> Option<&str> {
+        match self {
+            ObjectTypeError::SchemaTypeValidationError(error) => error.custom_message(),
+            _ => None,
+        }
+    }
+}
+
+serialize_error_code!(ObjectTypeError);
+
 impl From<SchemaTypeValidationError> for ObjectTypeError {
     fn from(value: SchemaTypeValidationError) -> Self {
         ObjectTypeError::SchemaTypeValidationError(Box::new(value))
     }
 }
 
+/// Keys of an object that belong to other schemas that validate the same object, like the keys
+/// declared by the other variants of an [AllOfType]. Objects that use the default policy for
+/// additional keys don't reject these keys in strict mode, so strict objects can still be
+/// combined. Objects that configure [AdditionalProperties] themselves are not affected. Keys that
+/// no schema declares are never shared, see [SchemaType::declares_key].
+///
+/// [AllOfType]: crate::schema_type::advanced_type::all_of_type::AllOfType
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum SharedKeys<'a> {
+    /// The keys declared by any of the schemas, like the variants of an [AllOfType].
+    ///
+    /// [AllOfType]: crate::schema_type::advanced_type::all_of_type::AllOfType
+    Declared(&'a [SchemaType]),
+
+    /// The keys declared by the condition or one of the branches of an [IfType].
+    ///
+    /// [IfType]: crate::schema_type::advanced_type::if_type::IfType
+    If(&'a IfType),
+
+    /// The keys known to an [ObjectType], which are shared with its dependent schemas.
+    Object(&'a ObjectType),
+
+    /// A single key, like the discriminator of a [TaggedType].
+    ///
+    /// [TaggedType]: crate::schema_type::advanced_type::tagged_type::TaggedType
+    Key(&'a str),
+
+    /// Keys that have already been collected for the object, see [ValidationContext::share].
+    Keys(&'a [String]),
+}
+
+impl SharedKeys<'_> {
+    pub(crate) fn contains(&self, key: &str, target_map: &Map<String, Value>, scope: ValidationScope) -> bool {
+        match self {
+            SharedKeys::Declared(schemas) => schemas.iter()
+                .any(|schema| schema.declares_key(key, target_map, scope)),
+            SharedKeys::If(if_type) => if_type.declares_key(key, target_map, scope),
+            SharedKeys::Object(object_type) => object_type.is_known_key(key, target_map, scope),
+            SharedKeys::Key(shared) => *shared == key,
+            SharedKeys::Keys(shared) => shared.iter().any(|shared| shared == key),
+        }
+    }
+}
+
+/// Determines what happens with keys in an object that are not part of the schema. This is
+/// (de)serialized as `true` for [AdditionalProperties::Allow], `false` for
+/// [AdditionalProperties::Reject] or as a schema to validate the values against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "AdditionalPropertiesValue", into = "AdditionalPropertiesValue")]
+pub enum AdditionalProperties {
+    /// Additional keys are ignored.
+    Allow,
+
+    /// Additional keys result in a [ObjectTypeError::UnexpectedKey] error.
+    Reject,
+
+    /// The values of additional keys should match the given schema.
+    Schema(Box<SchemaType>),
+}
+
+impl AdditionalProperties {
+    /// Returns the policy for the keys of the object that are not known to the schema, together
+    /// with those keys. Objects without a policy of their own reject the keys that are not shared
+    /// if the scope has strict objects and allow all keys otherwise, in which case `None` is
+    /// returned.
+    pub(crate) fn additional_keys<'a, 's>(
+        additional_properties: Option<&'a AdditionalProperties>,
+        is_known_key: impl Fn(&str) -> bool + 's,
+        target_map: &'a Map<String, Value>,
+        scope: ValidationScope<'s>,
+    ) -> Option<(&'a AdditionalProperties, impl Iterator<Item = (&'a String, &'a Value)> + 's)>
+    where
+        'a: 's,
+    {
+        let policy = match additional_properties {
+            Some(AdditionalProperties::Allow) => return None,
+            Some(policy) => policy,
+            None if scope.strict_objects() => &AdditionalProperties::Reject,
+            None => return None,
+        };
+
+        let keys = target_map.iter()
+            .filter(move |(key, _)| !is_known_key(key))
+            .filter(move |(key, _)| additional_properties.is_some() || !scope.is_shared(key, target_map));
+
+        Some((policy, keys))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum AdditionalPropertiesValue {
+    Bool(bool),
+    Schema(Box<SchemaType>),
+}
+
+impl From<AdditionalPropertiesValue> for AdditionalProperties {
+    fn from(value: AdditionalPropertiesValue) -> Self {
+        match value {
+            AdditionalPropertiesValue::Bool(true) => AdditionalProperties::Allow,
+            AdditionalPropertiesValue::Bool(false) => AdditionalProperties::Reject,
+            AdditionalPropertiesValue::Schema(schema) => AdditionalProperties::Schema(schema),
+        }
+    }
+}
+
+impl From<AdditionalProperties> for AdditionalPropertiesValue {
+    fn from(value: AdditionalProperties) -> Self {
+        match value {
+            AdditionalProperties::Allow => AdditionalPropertiesValue::Bool(true),
+            AdditionalProperties::Reject => AdditionalPropertiesValue::Bool(false),
+            AdditionalProperties::Schema(schema) => AdditionalPropertiesValue::Schema(schema),
+        }
+    }
+}
+
 /// This type checks for the exact keys. This differs from [SchemaType::Object] in that it cannot
 /// resolve to any other advanced type, so it allows for '$' or '?' to be used for something else.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ObjectType {
     pub object: HashMap<String, SchemaType>,
+
+    /// What to do with keys that are not in [ObjectType::object]. If not set, additional keys are
+    /// allowed, unless the object is validated by a [Schema] with strict objects.
+    ///
+    /// [Schema]: crate::schema::Schema
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_properties: Option<AdditionalProperties>,
+
+    /// Keys that are required when another key is present. Maps the key that triggers the
+    /// requirement to the keys that are then required.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub dependent_required: HashMap<String, Vec<String>>,
+
+    /// Schemas the complete object should match when a key is present. Maps the key that
+    /// triggers the schema to the schema.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub dependent_schemas: HashMap<String, SchemaType>,
+
+    /// Rules that involve multiple values in the object, like a date range or a password
+    /// confirmation. These are checked after the keys have been validated.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub constraints: Vec<ObjectConstraint>,
 }
 
 impl Display for ObjectType {
@@ -41,7 +240,11 @@ impl Display for ObjectType {
 impl From<HashMap<String, SchemaType>> for ObjectType {
     fn from(value: HashMap<String, SchemaType>) -> Self {
         ObjectType {
-            object: value
+            object: value,
+            additional_properties: None,
+            dependent_required: HashMap::new(),
+            dependent_schemas: HashMap::new(),
+            constraints: vec![],
         }
     }
 }
@@ -49,23 +252,484 @@ impl From<HashMap<String, SchemaType>> for ObjectType {
 impl Validator for ObjectType {
     type E = ObjectTypeError;
 
-    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
         let Value::Object(target_map) = value else {
             return Err(ObjectTypeError::NotAnObject);
         };
 
         for (key, schema) in &self.object {
             let Some(value) = target_map.get(key) else {
-                if let SchemaType::Advanced(AdvancedType::Optional(_)) = schema {
-                    return Ok(());
+                if let Some(AdvancedType::Optional(_)) = schema.as_advanced() {
+                    continue;
                 };
 
                 return Err(ObjectTypeError::MissingObjectKey(key.to_string()));
             };
 
-            schema.validate(value)?;
+            schema.validate_in(value, scope.nested())?;
+        }
+
+        let dependent_required = present_triggers(&self.dependent_required, target_map);
+
+        if let Some((_, error)) = ObjectType::missing_dependent_keys(dependent_required, target_map).next() {
+            return Err(error);
+        }
+
+        for (trigger, schema) in present_triggers(&self.dependent_schemas, target_map) {
+            scope.share(SharedKeys::Object(self), |scope| {
+                schema.validate_in(value, scope)
+                    .map_err(|error| ObjectTypeError::DependentSchemaError(trigger.to_string(), Box::new(error)))
+            })?;
+        }
+
+        for constraint in &self.constraints {
+            constraint.validate(value)?;
+        }
+
+        let additional_keys = AdditionalProperties::additional_keys(
+            self.additional_properties.as_ref(),
+            |key| self.is_known_key(key, target_map, scope),
+            target_map,
+            scope,
+        );
+
+        let Some((policy, additional_keys)) = additional_keys else {
+            return Ok(());
+        };
+
+        for (key, value) in additional_keys {
+            match policy {
+                AdditionalProperties::Allow => {}
+                AdditionalProperties::Reject => {
+                    return Err(ObjectTypeError::UnexpectedKey(key.to_string()));
+                }
+                AdditionalProperties::Schema(schema) => schema.validate_in(value, scope.nested())?,
+            }
         }
 
         Ok(())
     }
 }
+
+/// Returns the entries of a dependency map whose key is present in the object, sorted by key.
+fn present_triggers<'a, T>(
+    dependencies: &'a HashMap<String, T>,
+    target_map: &Map<String, Value>,
+) -> Vec<(&'a String, &'a T)> {
+    let mut present = dependencies.iter()
+        .filter(|(trigger, _)| target_map.contains_key(*trigger))
+        .collect::<Vec<(&String, &T)>>();
+
+    present.sort_by_key(|(trigger, _)| *trigger);
+    present
+}
+
+impl ObjectType {
+    /// Returns whether the key of the object is described by this object type. Besides the keys
+    /// in [ObjectType::object], these are the keys named in [ObjectType::dependent_required] and
+    /// the keys declared by [ObjectType::dependent_schemas], so strict objects don't reject them.
+    /// Keys of dependencies are only known if the trigger of the dependency is present.
+    pub(crate) fn is_known_key(&self, key: &str, target_map: &Map<String, Value>, scope: ValidationScope) -> bool {
+        if self.object.contains_key(key) {
+            return true;
+        }
+
+        let required = self.dependent_required.iter()
+            .filter(|(trigger, _)| target_map.contains_key(*trigger))
+            .any(|(trigger, required)| trigger == key || required.iter().any(|required| required == key));
+
+        required || self.dependent_schemas.iter()
+            .filter(|(trigger, _)| target_map.contains_key(*trigger))
+            .any(|(trigger, schema)| trigger == key || schema.declares_key(key, target_map, scope))
+    }
+
+    /// Returns an error for every key required by the dependencies that is missing from the
+    /// object, together with the trigger that requires it. Dependencies whose trigger is not
+    /// present are skipped. This is shared with the [CompiledSchema].
+    ///
+    /// [CompiledSchema]: crate::compiled_schema::CompiledSchema
+    pub(crate) fn missing_dependent_keys<'a>(
+        dependencies: impl IntoIterator<Item = (&'a String, &'a Vec<String>)>,
+        target_map: &'a Map<String, Value>,
+    ) -> impl Iterator<Item = (&'a String, ObjectTypeError)> {
+        dependencies.into_iter()
+            .filter(|(trigger, _)| target_map.contains_key(*trigger))
+            .flat_map(move |(trigger, required)| {
+                required.iter()
+                    .filter(|key| !target_map.contains_key(*key))
+                    .map(move |key| (trigger, ObjectTypeError::MissingDependentKey(key.to_string(), trigger.to_string())))
+            })
+    }
+
+    /// Reports the keys required by [ObjectType::dependent_required] that are missing and the
+    /// errors of the [ObjectType::dependent_schemas] that apply to the object.
+    fn report_dependencies(&self, value: &Value, target_map: &Map<String, Value>, context: &mut ValidationContext) {
+        let dependent_required = present_triggers(&self.dependent_required, target_map);
+
+        for (trigger, error) in ObjectType::missing_dependent_keys(dependent_required, target_map) {
+            context.enter_schema("dependentRequired", |context| {
+                context.enter_schema(trigger, |context| {
+                    context.report(AdvancedTypeValidationError::ObjectError(error));
+                });
+            });
+        }
+
+        for (trigger, schema) in present_triggers(&self.dependent_schemas, target_map) {
+            context.enter_schema("dependentSchemas", |context| {
+                context.enter_schema(trigger, |context| {
+                    context.share(value, SharedKeys::Object(self), |context| {
+                        context.wrap_errors(
+                            |context| schema.report(value, context),
+                            |error| AdvancedTypeValidationError::ObjectError(
+                                ObjectTypeError::DependentSchemaError(trigger.to_string(), Box::new(error))
+                            ).into(),
+                        );
+                    });
+                });
+            });
+        }
+    }
+
+    /// Reports the errors for all keys in the provided object. This is shared with the
+    /// [SchemaType::Object] shorthand, which stores the keys directly in the schema node instead of
+    /// under `object`. Keys are checked in sorted order so the resulting report is stable.
+    pub(crate) fn report_keys(
+        object: &HashMap<String, SchemaType>,
+        target_map: &Map<String, Value>,
+        context: &mut ValidationContext,
+    ) {
+        let mut keys = object.keys().collect::<Vec<&String>>();
+        keys.sort();
+
+        for key in keys {
+            let schema = &object[key];
+
+            context.enter_schema(key, |context| {
+                let Some(value) = target_map.get(key) else {
+                    if let Some(AdvancedType::Optional(_)) = schema.as_advanced() {
+                        return;
+                    };
+
+                    context.report(AdvancedTypeValidationError::ObjectError(
+                        ObjectTypeError::MissingObjectKey(key.to_string())
+                    ));
+                    return;
+                };
+
+                context.enter_instance(key, |context| schema.report(value, context));
+            });
+        }
+    }
+
+    /// Reports the keys in the object that are not part of the schema according to the given
+    /// policy, see [AdditionalProperties::additional_keys]. Also shared with the
+    /// [SchemaType::Object] shorthand.
+    pub(crate) fn report_additional_keys(
+        is_known_key: impl Fn(&str, ValidationScope) -> bool,
+        additional_properties: Option<&AdditionalProperties>,
+        target_map: &Map<String, Value>,
+        context: &mut ValidationContext,
+    ) {
+        let scope = context.scope();
+        let Some((policy, additional_keys)) = AdditionalProperties::additional_keys(
+            additional_properties,
+            |key| is_known_key(key, scope),
+            target_map,
+            scope,
+        ) else {
+            return;
+        };
+
+        // The keys are collected first, as the scope borrows the context while checking.
+        let additional_keys = additional_keys.collect::<Vec<(&String, &Value)>>();
+
+        for (key, value) in additional_keys {
+            match policy {
+                AdditionalProperties::Allow => {}
+                AdditionalProperties::Reject => {
+                    context.enter_instance(key, |context| {
+                        context.report(AdvancedTypeValidationError::ObjectError(
+                            ObjectTypeError::UnexpectedKey(key.to_string())
+                        ));
+                    });
+                }
+                AdditionalProperties::Schema(schema) => {
+                    context.enter_schema("additionalProperties", |context| {
+                        context.enter_instance(key, |context| schema.report(value, context));
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl ReportingValidator for ObjectType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        let Value::Object(target_map) = value else {
+            context.report(AdvancedTypeValidationError::ObjectError(ObjectTypeError::NotAnObject));
+            return;
+        };
+
+        context.enter_schema("object", |context| {
+            ObjectType::report_keys(&self.object, target_map, context);
+        });
+
+        self.report_dependencies(value, target_map, context);
+
+        context.enter_schema("constraints", |context| {
+            for (i, constraint) in self.constraints.iter().enumerate() {
+                context.enter_schema(i, |context| {
+                    context.report_result(constraint.validate(value)
+                        .map_err(|error| AdvancedTypeValidationError::ObjectError(error.into())));
+                });
+            }
+        });
+
+        ObjectType::report_additional_keys(
+            |key, scope| self.is_known_key(key, target_map, scope),
+            self.additional_properties.as_ref(),
+            target_map,
+            context,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use serde_json::json;
+    use crate::schema::validation_scope::ValidationScope;
+    use crate::schema_type::advanced_type::object_type::{AdditionalProperties, ObjectType, ObjectTypeError};
+    use crate::schema_type::advanced_type::object_type::object_constraint::ObjectConstraintError;
+    use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+    use crate::schema_type::basic_type::BasicTypeValidationError;
+    use crate::schema_type::SchemaTypeValidationError;
+    use crate::traits::reporting_validator::ReportingValidator;
+    use crate::schema_type::advanced_type::optional_type::OptionalType;
+    use crate::schema_type::basic_type::BasicType;
+    use crate::schema_type::SchemaType;
+    use crate::traits::validator::Validator;
+
+    #[test]
+    fn missing_optional_key_does_not_skip_other_keys() {
+        let object_type = ObjectType::from(HashMap::from([
+            ("a".to_string(), OptionalType::from(SchemaType::Basic(BasicType::String)).into()),
+            ("b".to_string(), BasicType::String.into()),
+        ]));
+
+        assert_eq!(
+            object_type.validate(&json!({})),
+            Err(ObjectTypeError::MissingObjectKey("b".to_string()))
+        );
+    }
+
+    fn user_type(additional_properties: AdditionalProperties) -> ObjectType {
+        ObjectType {
+            object: HashMap::from([
+                ("email".to_string(), BasicType::String.into()),
+            ]),
+            additional_properties: Some(additional_properties),
+            ..ObjectType::from(HashMap::new())
+        }
+    }
+
+    #[test]
+    fn additional_keys_are_allowed() {
+        let object_type = user_type(AdditionalProperties::Allow);
+
+        assert_eq!(object_type.validate(&json!({ "email": "", "emial": "" })), Ok(()));
+    }
+
+    #[test]
+    fn additional_keys_are_rejected() {
+        let object_type = user_type(AdditionalProperties::Reject);
+
+        assert_eq!(object_type.validate(&json!({ "email": "" })), Ok(()));
+        assert_eq!(
+            object_type.validate(&json!({ "email": "", "emial": "" })),
+            Err(ObjectTypeError::UnexpectedKey("emial".to_string()))
+        );
+
+        let report = object_type.validate_all(&json!({ "email": "", "emial": "" }));
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].instance_path.to_json_pointer(), "/emial");
+    }
+
+    #[test]
+    fn additional_keys_are_validated_against_schema() {
+        let object_type = user_type(AdditionalProperties::Schema(Box::new(BasicType::Number.into())));
+
+        assert_eq!(object_type.validate(&json!({ "email": "", "age": 10 })), Ok(()));
+        assert_eq!(
+            object_type.validate(&json!({ "email": "", "age": "10" })),
+            Err(ObjectTypeError::SchemaTypeValidationError(Box::new(
+                SchemaTypeValidationError::BasicTypeValidationError(
+                    BasicTypeValidationError::IncorrectType(BasicType::Number, json!("10"))
+                )
+            )))
+        );
+
+        let report = object_type.validate_all(&json!({ "email": 10, "age": "10" }));
+        assert_eq!(report.errors.len(), 2);
+        assert_eq!(report.errors[1].instance_path.to_json_pointer(), "/age");
+        assert_eq!(report.errors[1].schema_path.to_json_pointer(), "/additionalProperties");
+        assert_eq!(
+            report.errors[0].error,
+            SchemaTypeValidationError::BasicTypeValidationError(
+                BasicTypeValidationError::IncorrectType(BasicType::String, json!(10))
+            )
+        );
+        assert!(!matches!(
+            report.errors[1].error,
+            SchemaTypeValidationError::AdvancedTypeValidationError(AdvancedTypeValidationError::ObjectError(_))
+        ));
+    }
+
+    #[test]
+    fn additional_properties_is_deserialized_correctly() {
+        let strict: ObjectType = serde_json::from_value(json!({
+            "object": { "email": "string" },
+            "additionalProperties": false,
+        }))
+            .unwrap();
+
+        assert_eq!(strict, user_type(AdditionalProperties::Reject));
+
+        let with_schema: ObjectType = serde_json::from_value(json!({
+            "object": { "email": "string" },
+            "additionalProperties": "number",
+        }))
+            .unwrap();
+
+        assert_eq!(with_schema, user_type(AdditionalProperties::Schema(Box::new(BasicType::Number.into()))));
+
+        let default: ObjectType = serde_json::from_value(json!({
+            "object": { "email": "string" },
+        }))
+            .unwrap();
+
+        assert_eq!(default.additional_properties, None);
+    }
+
+    #[test]
+    fn dependent_keys_are_required_when_the_trigger_is_present() {
+        let object_type: ObjectType = serde_json::from_value(json!({
+            "object": { "name": "string" },
+            "dependentRequired": { "creditCard": ["billingAddress"] },
+        }))
+            .unwrap();
+
+        assert_eq!(object_type.validate(&json!({ "name": "" })), Ok(()));
+        assert_eq!(object_type.validate(&json!({ "name": "", "creditCard": 1, "billingAddress": "" })), Ok(()));
+        assert_eq!(
+            object_type.validate(&json!({ "name": "", "creditCard": 1 })),
+            Err(ObjectTypeError::MissingDependentKey("billingAddress".to_string(), "creditCard".to_string()))
+        );
+
+        let report = object_type.validate_all(&json!({ "name": "", "creditCard": 1 }));
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].schema_path.to_json_pointer(), "/dependentRequired/creditCard");
+        assert_eq!(
+            report.errors[0].error.to_string(),
+            "Missing object key: 'billingAddress', which is required when 'creditCard' is present"
+        );
+    }
+
+    #[test]
+    fn dependent_schemas_apply_to_the_object_when_the_trigger_is_present() {
+        let object_type: ObjectType = serde_json::from_value(json!({
+            "object": { "name": "string" },
+            "dependentSchemas": {
+                "discount": { "couponCode": "filledString" },
+            },
+        }))
+            .unwrap();
+
+        assert_eq!(object_type.validate(&json!({ "name": "" })), Ok(()));
+        assert_eq!(object_type.validate(&json!({ "name": "", "discount": 5, "couponCode": "SAVE" })), Ok(()));
+        assert_eq!(
+            object_type.validate(&json!({ "name": "", "discount": 5 })).unwrap_err().to_string(),
+            "When 'discount' is present: Missing object key: 'couponCode'"
+        );
+
+        let report = object_type.validate_all(&json!({ "name": "", "discount": 5, "couponCode": "" }));
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].instance_path.to_json_pointer(), "/couponCode");
+        assert_eq!(report.errors[0].schema_path.to_json_pointer(), "/dependentSchemas/discount/couponCode");
+        assert!(matches!(
+            report.errors[0].error,
+            SchemaTypeValidationError::AdvancedTypeValidationError(AdvancedTypeValidationError::ObjectError(
+                ObjectTypeError::DependentSchemaError(_, _)
+            ))
+        ));
+    }
+
+    #[test]
+    fn strict_objects_allow_the_keys_of_dependencies() {
+        let object_type: ObjectType = serde_json::from_value(json!({
+            "object": { "name": "string" },
+            "dependentRequired": { "creditCard": ["billingAddress"] },
+            "dependentSchemas": {
+                "discount": { "couponCode": "filledString" },
+            },
+        }))
+            .unwrap();
+
+        let scope = ValidationScope::default().with_strict_objects(true);
+        let valid = json!({ "name": "", "creditCard": 1, "billingAddress": "", "discount": 5, "couponCode": "SAVE" });
+        let invalid = json!({ "name": "", "creditCard": 1, "billingAddress": "", "nickname": "" });
+
+        assert_eq!(object_type.validate_in(&valid, scope), Ok(()));
+        assert!(object_type.validate_all_in(&valid, scope).is_valid());
+        assert_eq!(
+            object_type.validate_in(&invalid, scope),
+            Err(ObjectTypeError::UnexpectedKey("nickname".to_string()))
+        );
+
+        let report = object_type.validate_all_in(&invalid, scope);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].instance_path.to_json_pointer(), "/nickname");
+
+        // The keys of a dependency are only known while its trigger is present.
+        assert_eq!(
+            object_type.validate_in(&json!({ "name": "", "billingAddress": "" }), scope),
+            Err(ObjectTypeError::UnexpectedKey("billingAddress".to_string()))
+        );
+        assert_eq!(
+            object_type.validate_in(&json!({ "name": "", "couponCode": "SAVE" }), scope),
+            Err(ObjectTypeError::UnexpectedKey("couponCode".to_string()))
+        );
+        assert_eq!(object_type.validate(&json!({ "name": "", "couponCode": "SAVE" })), Ok(()));
+    }
+
+    #[test]
+    fn constraints_are_checked_after_the_keys() {
+        let object_type: ObjectType = serde_json::from_value(json!({
+            "object": {
+                "password": "filledString",
+                "confirm": "filledString",
+                "phone": { "$": "optional", "type": "string" },
+                "email": { "$": "optional", "type": "string" },
+            },
+            "constraints": [
+                { "$": "equal", "left": "/confirm", "right": "/password" },
+                { "$": "atLeastOneOf", "paths": ["/phone", "/email"] },
+            ],
+        }))
+            .unwrap();
+
+        assert_eq!(object_type.validate(&json!({ "password": "a", "confirm": "a", "phone": "06" })), Ok(()));
+        assert_eq!(
+            object_type.validate(&json!({ "password": "a", "confirm": "b", "phone": "06" })),
+            Err(ObjectTypeError::ConstraintError(
+                ObjectConstraintError::NotEqual("/confirm".to_string(), "/password".to_string())
+            ))
+        );
+
+        let report = object_type.validate_all(&json!({ "password": "a", "confirm": "b" }));
+        assert_eq!(report.errors.len(), 2);
+        assert_eq!(report.errors[0].instance_path.to_json_pointer(), "");
+        assert_eq!(report.errors[0].schema_path.to_json_pointer(), "/constraints/0");
+        assert_eq!(report.errors[1].schema_path.to_json_pointer(), "/constraints/1");
+    }
+}
@@ -0,0 +1,286 @@
+use std::cmp::Ordering;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+use crate::shared::{check_json_pointer, compare_numbers, json_equals, json_pointer};
+use crate::traits::validator::Validator;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+
+#[derive(Debug, PartialEq, Error)]
+pub enum ObjectConstraintError {
+    #[error("The value at '{0}' should be equal to the value at '{1}'")]
+    NotEqual(String, String),
+
+    #[error("The value at '{0}' should be less than the value at '{1}'")]
+    NotLessThan(String, String),
+
+    #[error("The value at '{0}' should be less than or equal to the value at '{1}'")]
+    NotLessThanOrEqual(String, String),
+
+    #[error("The values at '{0}' and '{1}' cannot be compared")]
+    NotComparable(String, String),
+
+    #[error("At least one of these values should be present: {}", .0.join(", "))]
+    NonePresent(Vec<String>),
+
+    #[error("Only one of these values can be present, but got: {}", .0.join(", "))]
+    MultiplePresent(Vec<String>),
+}
+
+impl ErrorCode for ObjectConstraintError {
+    fn code(&self) -> &'static str {
+        match self {
+            ObjectConstraintError::NotEqual(_, _) => "constraint.notEqual",
+            ObjectConstraintError::NotLessThan(_, _) => "constraint.notLessThan",
+            ObjectConstraintError::NotLessThanOrEqual(_, _) => "constraint.notLessThanOrEqual",
+            ObjectConstraintError::NotComparable(_, _) => "constraint.notComparable",
+            ObjectConstraintError::NonePresent(_) => "constraint.nonePresent",
+            ObjectConstraintError::MultiplePresent(_) => "constraint.multiplePresent",
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            ObjectConstraintError::NotEqual(left, right)
+            | ObjectConstraintError::NotLessThan(left, right)
+            | ObjectConstraintError::NotLessThanOrEqual(left, right)
+            | ObjectConstraintError::NotComparable(left, right) => {
+                to_params(json!({ "left": left, "right": right }))
+            }
+            ObjectConstraintError::NonePresent(paths)
+            | ObjectConstraintError::MultiplePresent(paths) => to_params(json!({ "paths": paths })),
+        }
+    }
+}
+
+serialize_error_code!(ObjectConstraintError);
+
+/// A rule that involves multiple values in an object, set through [ObjectType::constraints].
+/// Values are referred to by a JSON pointer relative to the object, like `/password` or
+/// `/period/start`. Paths that are not a JSON pointer, like `password`, are rejected when the
+/// constraint is deserialized.
+///
+/// Comparisons only apply when both values are present, so whether a value is required is still
+/// decided by the schema of the object. Values count as present when they are not `null`.
+///
+/// [ObjectType::constraints]: crate::schema_type::advanced_type::object_type::ObjectType::constraints
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ObjectConstraint {
+    /// The values should be equal, like a password and its confirmation.
+    Equal {
+        #[serde(deserialize_with = "deserialize_pointer")]
+        left: String,
+        #[serde(deserialize_with = "deserialize_pointer")]
+        right: String,
+    },
+
+    /// The left value should be less than the right value, or equal to it when `or_equal` is set.
+    /// Numbers are compared by their numeric value and strings are compared lexicographically, byte
+    /// by byte. Dates like those checked by [StringFormat::Date] are ordered correctly this way,
+    /// but dates with a time only are when they use the same format and are all in UTC: comparing
+    /// `2024-01-01T10:00:00+02:00` with `2024-01-01T09:00:00Z` gives the wrong answer.
+    ///
+    /// [StringFormat::Date]: crate::schema_type::string_format::StringFormat::Date
+    LessThan {
+        #[serde(deserialize_with = "deserialize_pointer")]
+        left: String,
+        #[serde(deserialize_with = "deserialize_pointer")]
+        right: String,
+        #[serde(default)]
+        or_equal: bool,
+    },
+
+    /// At least one of the values should be present.
+    AtLeastOneOf {
+        #[serde(deserialize_with = "deserialize_pointers")]
+        paths: Vec<String>,
+    },
+
+    /// At most one of the values can be present.
+    MutuallyExclusive {
+        #[serde(deserialize_with = "deserialize_pointers")]
+        paths: Vec<String>,
+    },
+}
+
+fn deserialize_pointer<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let pointer = String::deserialize(deserializer)?;
+    check_json_pointer(&pointer)?;
+
+    Ok(pointer)
+}
+
+fn deserialize_pointers<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let pointers = Vec::<String>::deserialize(deserializer)?;
+
+    for pointer in &pointers {
+        check_json_pointer(pointer)?;
+    }
+
+    Ok(pointers)
+}
+
+/// Returns the value at the given pointer, if it is present.
+fn present<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    json_pointer(value, path)
+        .filter(|value| !value.is_null())
+}
+
+fn compare(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => compare_numbers(left, right),
+        (Value::String(left), Value::String(right)) => Some(left.cmp(right)),
+        _ => None,
+    }
+}
+
+impl Validator for ObjectConstraint {
+    type E = ObjectConstraintError;
+
+    /// Checks the constraint against the object that contains the values.
+    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+        match self {
+            ObjectConstraint::Equal { left, right } => {
+                let (Some(left_value), Some(right_value)) = (present(value, left), present(value, right)) else {
+                    return Ok(());
+                };
+
+                if !json_equals(left_value, right_value) {
+                    return Err(ObjectConstraintError::NotEqual(left.to_string(), right.to_string()));
+                }
+            }
+            ObjectConstraint::LessThan { left, right, or_equal } => {
+                let (Some(left_value), Some(right_value)) = (present(value, left), present(value, right)) else {
+                    return Ok(());
+                };
+
+                let Some(ordering) = compare(left_value, right_value) else {
+                    return Err(ObjectConstraintError::NotComparable(left.to_string(), right.to_string()));
+                };
+
+                match (ordering, or_equal) {
+                    (Ordering::Less, _) | (Ordering::Equal, true) => {}
+                    (_, true) => {
+                        return Err(ObjectConstraintError::NotLessThanOrEqual(left.to_string(), right.to_string()));
+                    }
+                    (_, false) => {
+                        return Err(ObjectConstraintError::NotLessThan(left.to_string(), right.to_string()));
+                    }
+                }
+            }
+            ObjectConstraint::AtLeastOneOf { paths } => {
+                if !paths.iter().any(|path| present(value, path).is_some()) {
+                    return Err(ObjectConstraintError::NonePresent(paths.to_vec()));
+                }
+            }
+            ObjectConstraint::MutuallyExclusive { paths } => {
+                let is_present = |path: &&String| present(value, path).is_some();
+
+                // The paths are only collected when the constraint fails.
+                if paths.iter().filter(is_present).count() > 1 {
+                    return Err(ObjectConstraintError::MultiplePresent(
+                        paths.iter().filter(is_present).cloned().collect()
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use crate::schema_type::advanced_type::object_type::object_constraint::{ObjectConstraint, ObjectConstraintError};
+    use crate::traits::validator::Validator;
+
+    fn parse_constraint(value: Value) -> ObjectConstraint {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn equal_values_are_checked() {
+        let constraint = parse_constraint(json!({ "$": "equal", "left": "/confirm", "right": "/password" }));
+
+        assert_eq!(constraint.validate(&json!({ "password": "secret", "confirm": "secret" })), Ok(()));
+        assert_eq!(constraint.validate(&json!({ "password": "secret" })), Ok(()));
+        assert_eq!(
+            constraint.validate(&json!({ "password": "secret", "confirm": "secrte" })),
+            Err(ObjectConstraintError::NotEqual("/confirm".to_string(), "/password".to_string()))
+        );
+    }
+
+    #[test]
+    fn values_are_compared_by_path() {
+        let constraint = parse_constraint(json!({
+            "$": "lessThan",
+            "left": "/period/start",
+            "right": "/period/end",
+            "orEqual": true,
+        }));
+
+        assert_eq!(constraint.validate(&json!({ "period": { "start": "2024-01-01", "end": "2024-01-01" } })), Ok(()));
+        assert_eq!(
+            constraint.validate(&json!({ "period": { "start": "2024-02-01", "end": "2024-01-01" } })),
+            Err(ObjectConstraintError::NotLessThanOrEqual("/period/start".to_string(), "/period/end".to_string()))
+        );
+
+        let constraint = parse_constraint(json!({ "$": "lessThan", "left": "/min", "right": "/max" }));
+
+        assert_eq!(constraint.validate(&json!({ "min": 9007199254740992.0, "max": 9007199254740993_u64 })), Ok(()));
+        assert_eq!(
+            constraint.validate(&json!({ "min": 9007199254740993_u64, "max": 9007199254740992.0 })),
+            Err(ObjectConstraintError::NotLessThan("/min".to_string(), "/max".to_string()))
+        );
+        assert_eq!(constraint.validate(&json!({ "min": 1, "max": 2.5 })), Ok(()));
+        assert_eq!(
+            constraint.validate(&json!({ "min": 2, "max": 2 })),
+            Err(ObjectConstraintError::NotLessThan("/min".to_string(), "/max".to_string()))
+        );
+        assert_eq!(
+            constraint.validate(&json!({ "min": 2, "max": "3" })),
+            Err(ObjectConstraintError::NotComparable("/min".to_string(), "/max".to_string()))
+        );
+    }
+
+    #[test]
+    fn presence_of_values_is_checked() {
+        let at_least_one = parse_constraint(json!({ "$": "atLeastOneOf", "paths": ["/phone", "/email"] }));
+
+        assert_eq!(at_least_one.validate(&json!({ "email": "alice@example.com" })), Ok(()));
+        assert_eq!(
+            at_least_one.validate(&json!({ "phone": null })).unwrap_err().to_string(),
+            "At least one of these values should be present: /phone, /email"
+        );
+
+        let exclusive = parse_constraint(json!({ "$": "mutuallyExclusive", "paths": ["/iban", "/card", "/paypal"] }));
+
+        assert_eq!(exclusive.validate(&json!({ "card": "4111", "paypal": null })), Ok(()));
+        assert_eq!(
+            exclusive.validate(&json!({ "iban": "NL00", "card": "4111", "paypal": "alice" })),
+            Err(ObjectConstraintError::MultiplePresent(vec![
+                "/iban".to_string(),
+                "/card".to_string(),
+                "/paypal".to_string(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn paths_without_a_leading_slash_are_rejected() {
+        let constraints = [
+            json!({ "$": "equal", "left": "confirm", "right": "/password" }),
+            json!({ "$": "lessThan", "left": "/min", "right": "max" }),
+            json!({ "$": "atLeastOneOf", "paths": ["/phone", "email"] }),
+        ];
+
+        for constraint in constraints {
+            let error = serde_json::from_value::<ObjectConstraint>(constraint.clone()).unwrap_err();
+            assert!(error.to_string().contains("is not a JSON Pointer"), "{}: {}", constraint, error);
+        }
+
+        assert!(serde_json::from_value::<ObjectConstraint>(json!({ "$": "equal", "left": "", "right": "/a" })).is_ok());
+    }
+}
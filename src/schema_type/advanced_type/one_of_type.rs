@@ -0,0 +1,149 @@
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+use crate::schema_type::SchemaType;
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt::{Debug, Display, Formatter};
+use thiserror::Error;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+
+#[derive(Debug, PartialEq, Error)]
+pub enum OneOfTypeError {
+    #[error("No matching variant. Expected exactly one of: {}", display_variants(.0))]
+    NoMatch(Vec<SchemaType>),
+
+    #[error("Expected exactly one matching variant, but variants {} matched", display_indices(.0))]
+    MultipleMatches(Vec<usize>),
+}
+
+impl ErrorCode for OneOfTypeError {
+    fn code(&self) -> &'static str {
+        match self {
+            OneOfTypeError::NoMatch(_) => "oneOf.noMatch",
+            OneOfTypeError::MultipleMatches(_) => "oneOf.multipleMatches",
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            OneOfTypeError::NoMatch(variants) => {
+                let variants = variants.iter()
+                    .map(|schema| schema.to_string())
+                    .collect::<Vec<String>>();
+
+                to_params(json!({ "variants": variants }))
+            }
+            OneOfTypeError::MultipleMatches(indices) => to_params(json!({ "indices": indices })),
+        }
+    }
+}
+
+serialize_error_code!(OneOfTypeError);
+
+fn display_variants(variants: &[SchemaType]) -> String {
+    variants
+        .iter()
+        .map(|schema| schema.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn display_indices(indices: &[usize]) -> String {
+    indices
+        .iter()
+        .map(|index| index.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Passes if the provided value matches exactly one of the provided type conditions.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OneOfType {
+    pub variants: Vec<SchemaType>,
+}
+
+impl Display for OneOfType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "one of: {}", display_variants(&self.variants))
+    }
+}
+
+impl Validator for OneOfType {
+    type E = OneOfTypeError;
+
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
+        let matches = self.variants
+            .iter()
+            .enumerate()
+            .filter(|(_, variant)| variant.validate_in(value, scope).is_ok())
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+
+        match matches.len() {
+            1 => Ok(()),
+            0 => Err(OneOfTypeError::NoMatch(self.variants.to_vec())),
+            _ => Err(OneOfTypeError::MultipleMatches(matches)),
+        }
+    }
+}
+
+impl ReportingValidator for OneOfType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        let result = self.validate_in(value, context.scope());
+        context.report_result(result.map_err(AdvancedTypeValidationError::from));
+    }
+}
+
+impl<const U: usize> From<[SchemaType; U]> for OneOfType {
+    fn from(value: [SchemaType; U]) -> Self {
+        OneOfType {
+            variants: value.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema_type::advanced_type::one_of_type::{OneOfType, OneOfTypeError};
+    use crate::schema_type::basic_type::BasicType;
+    use crate::schema_type::SchemaType;
+    use crate::traits::validator::Validator;
+    use serde_json::json;
+
+    #[test]
+    fn exactly_one_variant_must_match() {
+        let one_of_type = OneOfType::from([
+            SchemaType::Basic(BasicType::String),
+            SchemaType::Basic(BasicType::Number),
+        ]);
+
+        assert_eq!(one_of_type.validate(&json!("")), Ok(()));
+        assert_eq!(one_of_type.validate(&json!(10)), Ok(()));
+        assert_eq!(
+            one_of_type.validate(&json!(null)),
+            Err(OneOfTypeError::NoMatch(vec![
+                SchemaType::Basic(BasicType::String),
+                SchemaType::Basic(BasicType::Number),
+            ]))
+        );
+    }
+
+    #[test]
+    fn multiple_matching_variants_are_reported() {
+        let one_of_type = OneOfType::from([
+            SchemaType::Basic(BasicType::Number),
+            SchemaType::Basic(BasicType::String),
+            SchemaType::Basic(BasicType::U8),
+        ]);
+
+        assert_eq!(
+            one_of_type.validate(&json!(10)),
+            Err(OneOfTypeError::MultipleMatches(vec![0, 2]))
+        );
+        assert_eq!(one_of_type.validate(&json!(1000)), Ok(()));
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt::{Debug, Display, Formatter};
 use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::report::validation_context::ValidationContext;
+use crate::traits::reporting_validator::ReportingValidator;
 use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -27,16 +30,26 @@ impl Display for OptionalType {
 impl Validator for OptionalType {
     type E = SchemaTypeValidationError;
 
-    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
         if let Value::Null = value {
             return Ok(());
         }
 
-        self.kind.validate(value)?;
+        self.kind.validate_in(value, scope)?;
         Ok(())
     }
 }
 
+impl ReportingValidator for OptionalType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        if let Value::Null = value {
+            return;
+        }
+
+        context.enter_schema("type", |context| self.kind.report(value, context));
+    }
+}
+
 impl From<SchemaType> for OptionalType {
     fn from(value: SchemaType) -> Self {
         OptionalType {
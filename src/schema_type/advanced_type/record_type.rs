@@ -0,0 +1,292 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::advanced_string_type::{AdvancedStringType, StringValidationError};
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+use crate::schema_type::pattern::Pattern;
+use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+
+#[derive(Debug, PartialEq, Error)]
+pub enum RecordTypeError {
+    #[error("Expected an object, but got something else")]
+    NotAnObject,
+
+    #[error("Expected at least {1} properties, but got {0}")]
+    TooFewProperties(usize, usize),
+
+    #[error("Expected at most {1} properties, but got {0}")]
+    TooManyProperties(usize, usize),
+
+    #[error("Invalid key '{0}': {1}")]
+    InvalidKey(String, StringValidationError),
+
+    #[error("Invalid value for key '{0}': {1}")]
+    InvalidValue(String, Box<SchemaTypeValidationError>),
+}
+
+impl ErrorCode for RecordTypeError {
+    fn code(&self) -> &'static str {
+        match self {
+            RecordTypeError::NotAnObject => "record.notAnObject",
+            RecordTypeError::TooFewProperties(_, _) => "record.tooFewProperties",
+            RecordTypeError::TooManyProperties(_, _) => "record.tooManyProperties",
+            RecordTypeError::InvalidKey(_, _) => "record.invalidKey",
+            RecordTypeError::InvalidValue(_, _) => "record.invalidValue",
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            RecordTypeError::NotAnObject => Map::new(),
+            RecordTypeError::TooFewProperties(count, min) => to_params(json!({ "count": count, "min": min })),
+            RecordTypeError::TooManyProperties(count, max) => to_params(json!({ "count": count, "max": max })),
+            RecordTypeError::InvalidKey(key, error) => to_params(json!({ "key": key, "error": error })),
+            RecordTypeError::InvalidValue(key, error) => to_params(json!({ "key": key, "error": error })),
+        }
+    }
+}
+
+serialize_error_code!(RecordTypeError);
+
+/// Constraint for the keys of a [RecordType], either a regular expression or a complete
+/// [AdvancedStringType].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RecordKeyType {
+    Pattern(Pattern),
+    String(AdvancedStringType),
+}
+
+impl RecordKeyType {
+    pub(crate) fn validate_key(&self, key: &str) -> Result<(), StringValidationError> {
+        match self {
+            RecordKeyType::Pattern(pattern) => {
+                if !pattern.is_match(key) {
+                    return Err(StringValidationError::PatternMismatch(pattern.to_string()));
+                }
+
+                Ok(())
+            }
+            RecordKeyType::String(string_type) => {
+                string_type.validate_str(key)
+            }
+        }
+    }
+}
+
+/// Checks for an object with keys that are not known up front, like a dictionary. All values should
+/// match the same schema and the keys can optionally be constrained.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordType {
+    /// The shape that all values in the object should have.
+    pub values: Box<SchemaType>,
+
+    /// If set, all keys in the object should match this constraint.
+    pub keys: Option<RecordKeyType>,
+
+    /// If set, the object should have at least n number of keys.
+    pub min_properties: Option<usize>,
+
+    /// If set, the object should have at most n number of keys.
+    pub max_properties: Option<usize>,
+}
+
+impl RecordType {
+    /// Checks the number of keys in the object. This is shared with the [CompiledSchema].
+    ///
+    /// [CompiledSchema]: crate::compiled_schema::CompiledSchema
+    pub(crate) fn validate_size(
+        min_properties: Option<usize>,
+        max_properties: Option<usize>,
+        size: usize,
+    ) -> Result<(), RecordTypeError> {
+        if let Some(min_properties) = min_properties {
+            if size < min_properties {
+                return Err(RecordTypeError::TooFewProperties(size, min_properties));
+            }
+        }
+
+        if let Some(max_properties) = max_properties {
+            if size > max_properties {
+                return Err(RecordTypeError::TooManyProperties(size, max_properties));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for RecordType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "record of ")?;
+        Display::fmt(&self.values, f)
+    }
+}
+
+impl From<SchemaType> for RecordType {
+    fn from(value: SchemaType) -> Self {
+        RecordType {
+            values: Box::new(value),
+            keys: None,
+            min_properties: None,
+            max_properties: None,
+        }
+    }
+}
+
+impl Validator for RecordType {
+    type E = RecordTypeError;
+
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
+        let Value::Object(target_map) = value else {
+            return Err(RecordTypeError::NotAnObject);
+        };
+
+        RecordType::validate_size(self.min_properties, self.max_properties, target_map.len())?;
+
+        for (key, value) in target_map {
+            if let Some(keys) = &self.keys {
+                keys.validate_key(key)
+                    .map_err(|error| RecordTypeError::InvalidKey(key.to_string(), error))?;
+            }
+
+            self.values.validate_in(value, scope.nested())
+                .map_err(|error| RecordTypeError::InvalidValue(key.to_string(), Box::new(error)))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ReportingValidator for RecordType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        let Value::Object(target_map) = value else {
+            context.report(AdvancedTypeValidationError::RecordError(RecordTypeError::NotAnObject));
+            return;
+        };
+
+        if let Err(error) = RecordType::validate_size(self.min_properties, self.max_properties, target_map.len()) {
+            context.report(AdvancedTypeValidationError::RecordError(error));
+        }
+
+        for (key, value) in target_map {
+            context.enter_instance(key, |context| {
+                if let Some(keys) = &self.keys {
+                    if let Err(error) = keys.validate_key(key) {
+                        context.enter_schema("keys", |context| {
+                            context.report(AdvancedTypeValidationError::RecordError(
+                                RecordTypeError::InvalidKey(key.to_string(), error)
+                            ));
+                        });
+                    }
+                }
+
+                context.enter_schema("values", |context| self.values.report(value, context));
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::schema_type::advanced_type::advanced_string_type::{AdvancedStringType, StringValidationError};
+    use crate::schema_type::advanced_type::record_type::{RecordKeyType, RecordType, RecordTypeError};
+    use crate::schema_type::basic_type::{BasicType, BasicTypeValidationError};
+    use crate::schema_type::pattern::Pattern;
+    use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+    use crate::traits::reporting_validator::ReportingValidator;
+    use crate::traits::validator::Validator;
+
+    fn translations_type() -> RecordType {
+        RecordType {
+            keys: Some(RecordKeyType::Pattern(Pattern::try_from("^[a-z]{2}(-[A-Z]{2})?$").unwrap())),
+            ..RecordType::from(SchemaType::Basic(BasicType::FilledString))
+        }
+    }
+
+    #[test]
+    fn record_values_are_validated() {
+        let record_type = translations_type();
+
+        assert_eq!(record_type.validate(&json!({})), Ok(()));
+        assert_eq!(record_type.validate(&json!({ "en": "Hello", "nl-BE": "Hallo" })), Ok(()));
+        assert_eq!(
+            record_type.validate(&json!({ "en": "Hello", "nl": "" })),
+            Err(RecordTypeError::InvalidValue(
+                "nl".to_string(),
+                Box::new(SchemaTypeValidationError::BasicTypeValidationError(
+                    BasicTypeValidationError::EmptyString
+                ))
+            ))
+        );
+        assert_eq!(record_type.validate(&json!([])), Err(RecordTypeError::NotAnObject));
+    }
+
+    #[test]
+    fn record_keys_are_validated() {
+        let record_type = translations_type();
+
+        assert_eq!(
+            record_type.validate(&json!({ "english": "Hello" })),
+            Err(RecordTypeError::InvalidKey(
+                "english".to_string(),
+                StringValidationError::PatternMismatch("^[a-z]{2}(-[A-Z]{2})?$".to_string())
+            ))
+        );
+
+        let record_type = RecordType {
+            keys: Some(RecordKeyType::String(AdvancedStringType {
+                max_length: Some(2),
+                ..AdvancedStringType::default()
+            })),
+            ..RecordType::from(SchemaType::Basic(BasicType::String))
+        };
+
+        assert_eq!(record_type.validate(&json!({ "en": "Hello" })), Ok(()));
+        assert_eq!(
+            record_type.validate(&json!({ "eng": "Hello" })),
+            Err(RecordTypeError::InvalidKey("eng".to_string(), StringValidationError::StringTooLong(3, 2)))
+        );
+    }
+
+    #[test]
+    fn record_size_is_validated() {
+        let record_type = RecordType {
+            min_properties: Some(1),
+            max_properties: Some(2),
+            ..RecordType::from(SchemaType::Basic(BasicType::String))
+        };
+
+        assert_eq!(record_type.validate(&json!({})), Err(RecordTypeError::TooFewProperties(0, 1)));
+        assert_eq!(record_type.validate(&json!({ "a": "" })), Ok(()));
+        assert_eq!(
+            record_type.validate(&json!({ "a": "", "b": "", "c": "" })),
+            Err(RecordTypeError::TooManyProperties(3, 2))
+        );
+    }
+
+    #[test]
+    fn every_offending_key_is_reported() {
+        let report = translations_type().validate_all(&json!({
+            "en": "",
+            "english": "Hello",
+            "nl": "Hallo",
+        }));
+
+        let instance_paths = report.errors
+            .iter()
+            .map(|error| error.instance_path.to_json_pointer())
+            .collect::<Vec<String>>();
+
+        assert_eq!(instance_paths, vec!["/en", "/english"]);
+        assert_eq!(report.errors[1].schema_path.to_json_pointer(), "/keys");
+    }
+}
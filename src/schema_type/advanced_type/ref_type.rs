@@ -0,0 +1,228 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+use crate::schema_type::SchemaTypeValidationError;
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+
+#[derive(Debug, PartialEq, Error)]
+pub enum RefTypeError {
+    #[error("Unknown definition: '{0}'")]
+    UnknownDefinition(String),
+
+    #[error("Exceeded the maximum depth of {0} nested references")]
+    MaxDepthExceeded(usize),
+
+    #[error("{0}")]
+    SchemaTypeValidationError(Box<SchemaTypeValidationError>),
+}
+
+impl ErrorCode for RefTypeError {
+    fn code(&self) -> &'static str {
+        match self {
+            RefTypeError::UnknownDefinition(_) => "ref.unknownDefinition",
+            RefTypeError::MaxDepthExceeded(_) => "ref.maxDepthExceeded",
+            RefTypeError::SchemaTypeValidationError(error) => error.code(),
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            RefTypeError::UnknownDefinition(name) => to_params(json!({ "name": name })),
+            RefTypeError::MaxDepthExceeded(max) => to_params(json!({ "max": max })),
+            RefTypeError::SchemaTypeValidationError(error) => error.params(),
+        }
+    }
+
+    fn custom_message(&self) -> Option<&str> {
+        match self {
+            RefTypeError::SchemaTypeValidationError(error) => error.custom_message(),
+            _ => None,
+        }
+    }
+}
+
+serialize_error_code!(RefTypeError);
+
+impl From<SchemaTypeValidationError> for RefTypeError {
+    fn from(value: SchemaTypeValidationError) -> Self {
+        RefTypeError::SchemaTypeValidationError(Box::new(value))
+    }
+}
+
+/// Refers to a named schema in the [Definitions] of a [Schema], which makes it possible to share
+/// schemas and to describe recursive structures like trees. References are resolved against the
+/// definitions of the [ValidationScope] that is passed to [Validator::validate_in]. The [Schema]
+/// validates in a scope with its definitions and [CompiledSchema] resolves them when compiling.
+/// Without definitions, like when using [Validator::validate], a reference fails with
+/// [RefTypeError::UnknownDefinition].
+///
+/// Errors that are reported inside the definition have a schema path starting at the definition,
+/// like `/definitions/comment/text`, regardless of where the reference is.
+///
+/// [Definitions]: crate::schema::definitions::Definitions
+/// [Schema]: crate::schema::Schema
+/// [CompiledSchema]: crate::compiled_schema::CompiledSchema
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefType {
+    /// The name of the definition to validate against.
+    pub name: String,
+}
+
+impl Display for RefType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "reference to '{}'", self.name)
+    }
+}
+
+impl Validator for RefType {
+    type E = RefTypeError;
+
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
+        let (definition, scope) = scope.resolve(&self.name)?;
+
+        Ok(definition.validate_in(value, scope)?)
+    }
+}
+
+impl ReportingValidator for RefType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        let result = context.enter_definition(&self.name, |definition, context| {
+            definition.report(value, context);
+        });
+
+        context.report_result(result.map_err(AdvancedTypeValidationError::from));
+    }
+}
+
+impl From<&str> for RefType {
+    fn from(value: &str) -> Self {
+        RefType {
+            name: value.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use crate::schema::definitions::{Definitions, MAX_REF_DEPTH};
+    use crate::schema::validation_scope::ValidationScope;
+    use crate::schema_type::advanced_type::ref_type::{RefType, RefTypeError};
+    use crate::schema_type::SchemaType;
+    use crate::traits::reporting_validator::ReportingValidator;
+    use crate::traits::validator::Validator;
+
+    fn comment_definitions() -> Definitions {
+        serde_json::from_value(json!({
+            "comment": {
+                "text": "filledString",
+                "replies": [{ "$": "ref", "name": "comment" }],
+            },
+        }))
+            .unwrap()
+    }
+
+    fn nested_comment(depth: usize) -> Value {
+        (0..depth).fold(json!({ "text": "Last", "replies": [] }), |reply, _| {
+            json!({ "text": "Reply", "replies": [reply] })
+        })
+    }
+
+    #[test]
+    fn recursive_definitions_are_validated() {
+        let definitions = comment_definitions();
+        let scope = ValidationScope::new(&definitions);
+        let ref_type = RefType::from("comment");
+
+        assert_eq!(ref_type.validate_in(&nested_comment(5), scope), Ok(()));
+        assert!(ref_type.validate_in(&json!({
+            "text": "First",
+            "replies": [{ "text": "", "replies": [] }],
+        }), scope).is_err());
+    }
+
+    #[test]
+    fn reference_without_definitions_returns_an_error() {
+        assert_eq!(
+            RefType::from("comment").validate(&json!({})),
+            Err(RefTypeError::UnknownDefinition("comment".to_string()))
+        );
+
+        let definitions = comment_definitions();
+        assert_eq!(
+            RefType::from("post").validate_in(&json!({}), ValidationScope::new(&definitions)),
+            Err(RefTypeError::UnknownDefinition("post".to_string()))
+        );
+    }
+
+    #[test]
+    fn schema_types_with_references_are_validated_on_any_thread() {
+        let definitions = comment_definitions();
+        let post_type: SchemaType = serde_json::from_value(json!({
+            "title": "string",
+            "comments": [{ "$": "ref", "name": "comment" }],
+        }))
+            .unwrap();
+
+        let valid = json!({ "title": "Hello", "comments": [nested_comment(3)] });
+        let invalid = json!({ "title": "Hello", "comments": [{ "text": "", "replies": [] }] });
+
+        assert_eq!(post_type.validate_in(&valid, ValidationScope::new(&definitions)), Ok(()));
+
+        std::thread::scope(|threads| {
+            threads.spawn(|| {
+                let scope = ValidationScope::new(&definitions);
+
+                assert_eq!(post_type.validate_in(&valid, scope), Ok(()));
+                assert!(post_type.validate_in(&invalid, scope).is_err());
+                assert_eq!(post_type.validate_all_in(&invalid, scope).errors.len(), 1);
+            });
+        });
+    }
+
+    #[test]
+    fn deeply_nested_values_return_an_error() {
+        let definitions = comment_definitions();
+        let scope = ValidationScope::new(&definitions);
+        let ref_type = RefType::from("comment");
+
+        assert_eq!(ref_type.validate_in(&nested_comment(MAX_REF_DEPTH - 1), scope), Ok(()));
+        assert!(ref_type.validate_in(&nested_comment(MAX_REF_DEPTH), scope).is_err());
+
+        let report = ref_type.validate_all_in(&nested_comment(MAX_REF_DEPTH * 2), scope);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].error.to_string(), RefTypeError::MaxDepthExceeded(MAX_REF_DEPTH).to_string());
+    }
+
+    #[test]
+    fn errors_are_reported_at_the_definition() {
+        let definitions = comment_definitions();
+        let scope = ValidationScope::new(&definitions);
+
+        let report = RefType::from("comment").validate_all_in(&json!({
+            "text": "First",
+            "replies": [{ "text": "", "replies": [] }],
+        }), scope);
+
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].instance_path.to_json_pointer(), "/replies/0/text");
+        assert_eq!(report.errors[0].schema_path.to_json_pointer(), "/definitions/comment/text");
+
+        let post_type: SchemaType = serde_json::from_value(json!({
+            "comments": [{ "$": "ref", "name": "comment" }],
+        }))
+            .unwrap();
+
+        let report = post_type.validate_all_in(&json!({ "comments": [{ "text": "", "replies": [] }] }), scope);
+
+        assert_eq!(report.errors[0].instance_path.to_json_pointer(), "/comments/0/text");
+        assert_eq!(report.errors[0].schema_path.to_json_pointer(), "/definitions/comment/text");
+    }
+}
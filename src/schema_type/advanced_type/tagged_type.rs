@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+use crate::schema_type::advanced_type::object_type::SharedKeys;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum TaggedTypeError {
+    #[error("Expected an object, but got something else")]
+    NotAnObject,
+
+    #[error("Missing discriminator key: '{0}'")]
+    MissingDiscriminator(String),
+
+    #[error("Unknown tag {0}. Expected one of: {}", .1.join(", "))]
+    UnknownTag(Value, Vec<String>),
+
+    #[error("{0}")]
+    SchemaTypeValidationError(Box<SchemaTypeValidationError>),
+}
+
+impl ErrorCode for TaggedTypeError {
+    fn code(&self) -> &'static str {
+        match self {
+            TaggedTypeError::NotAnObject => "tagged.notAnObject",
+            TaggedTypeError::MissingDiscriminator(_) => "tagged.missingDiscriminator",
+            TaggedTypeError::UnknownTag(_, _) => "tagged.unknownTag",
+            TaggedTypeError::SchemaTypeValidationError(error) => error.code(),
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            TaggedTypeError::NotAnObject => Map::new(),
+            TaggedTypeError::MissingDiscriminator(discriminator) => {
+                to_params(json!({ "discriminator": discriminator }))
+            }
+            TaggedTypeError::UnknownTag(tag, tags) => to_params(json!({ "tag": tag, "tags": tags })),
+            TaggedTypeError::SchemaTypeValidationError(error) => error.params(),
+        }
+    }
+
+    fn custom_message(&self) -> Option<&str> {
+        match self {
+            TaggedTypeError::SchemaTypeValidationError(error) => error.custom_message(),
+            _ => None,
+        }
+    }
+}
+
+serialize_error_code!(TaggedTypeError);
+
+impl From<SchemaTypeValidationError> for TaggedTypeError {
+    fn from(value: SchemaTypeValidationError) -> Self {
+        TaggedTypeError::SchemaTypeValidationError(Box::new(value))
+    }
+}
+
+/// Discriminated union for objects. The value of the discriminator key is used to pick the variant
+/// to validate the object with, so unlike [AnyOfType] only a single variant is checked and the
+/// errors of that variant are returned. The variant is validated against the complete object,
+/// where the discriminator key is not treated as an additional key. Variants that configure
+/// [AdditionalProperties] themselves should include the discriminator key.
+///
+/// [AnyOfType]: crate::schema_type::advanced_type::any_of_type::AnyOfType
+/// [AdditionalProperties]: crate::schema_type::advanced_type::object_type::AdditionalProperties
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaggedType {
+    /// The key in the object that holds the tag.
+    pub discriminator: String,
+
+    /// Maps each tag to the schema the object should match when it has that tag.
+    pub variants: HashMap<String, SchemaType>,
+}
+
+impl TaggedType {
+    /// Returns the tag and the matching variant for the given object.
+    pub(crate) fn variant_for(&self, value: &Value) -> Result<(&String, &SchemaType), TaggedTypeError> {
+        let Value::Object(target_map) = value else {
+            return Err(TaggedTypeError::NotAnObject);
+        };
+
+        let Some(tag) = target_map.get(&self.discriminator) else {
+            return Err(TaggedTypeError::MissingDiscriminator(self.discriminator.to_string()));
+        };
+
+        let variant = tag.as_str()
+            .and_then(|tag| self.variants.get_key_value(tag));
+
+        let Some(variant) = variant else {
+            let mut tags = self.variants.keys()
+                .map(|tag| tag.to_string())
+                .collect::<Vec<String>>();
+
+            tags.sort();
+
+            return Err(TaggedTypeError::UnknownTag(tag.clone(), tags));
+        };
+
+        Ok(variant)
+    }
+
+    /// The discriminator is part of every variant, so objects in the variants don't need to list
+    /// it themselves.
+    pub(crate) fn discriminator_key(&self) -> SharedKeys<'_> {
+        SharedKeys::Key(&self.discriminator)
+    }
+
+    /// Returns whether the key is the discriminator or is declared by the variant that the tag of
+    /// the object selects, see [SchemaType::declares_key].
+    pub(crate) fn declares_key(&self, key: &str, target_map: &Map<String, Value>, scope: ValidationScope) -> bool {
+        if key == self.discriminator {
+            return true;
+        }
+
+        target_map.get(&self.discriminator)
+            .and_then(Value::as_str)
+            .and_then(|tag| self.variants.get(tag))
+            .is_some_and(|variant| variant.declares_key(key, target_map, scope))
+    }
+}
+
+impl Display for TaggedType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "tagged union on '{}'", self.discriminator)
+    }
+}
+
+impl Validator for TaggedType {
+    type E = TaggedTypeError;
+
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
+        let (_, variant) = self.variant_for(value)?;
+
+        scope.share(self.discriminator_key(), |scope| variant.validate_in(value, scope).map_err(TaggedTypeError::from))?;
+        Ok(())
+    }
+}
+
+impl ReportingValidator for TaggedType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        let (tag, variant) = match self.variant_for(value) {
+            Ok(variant) => variant,
+            Err(error) => {
+                context.report(AdvancedTypeValidationError::TaggedError(error));
+                return;
+            }
+        };
+
+        context.share(value, self.discriminator_key(), |context| {
+            context.enter_schema("variants", |context| {
+                context.enter_schema(tag, |context| variant.report(value, context));
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use serde_json::json;
+    use crate::schema_type::advanced_type::tagged_type::{TaggedType, TaggedTypeError};
+    use crate::schema_type::advanced_type::object_type::ObjectTypeError;
+    use crate::schema_type::advanced_type::AdvancedTypeValidationError;
+    use crate::schema_type::basic_type::BasicType;
+    use crate::schema_type::SchemaTypeValidationError;
+    use crate::traits::validator::Validator;
+
+    fn shape_type() -> TaggedType {
+        TaggedType {
+            discriminator: "kind".to_string(),
+            variants: HashMap::from([
+                ("circle".to_string(), HashMap::from([
+                    ("radius".to_string(), BasicType::Number.into()),
+                ]).into()),
+                ("square".to_string(), HashMap::from([
+                    ("size".to_string(), BasicType::Number.into()),
+                ]).into()),
+            ]),
+        }
+    }
+
+    #[test]
+    fn matching_variant_is_validated() {
+        let tagged_type = shape_type();
+
+        assert_eq!(tagged_type.validate(&json!({ "kind": "circle", "radius": 10 })), Ok(()));
+        assert_eq!(tagged_type.validate(&json!({ "kind": "square", "size": 10 })), Ok(()));
+    }
+
+    #[test]
+    fn only_errors_of_the_matching_variant_are_returned() {
+        let tagged_type = shape_type();
+
+        assert_eq!(
+            tagged_type.validate(&json!({ "kind": "circle", "size": 10 })),
+            Err(TaggedTypeError::SchemaTypeValidationError(Box::new(
+                SchemaTypeValidationError::AdvancedTypeValidationError(
+                    AdvancedTypeValidationError::ObjectError(
+                        ObjectTypeError::MissingObjectKey("radius".to_string())
+                    )
+                )
+            )))
+        );
+    }
+
+    #[test]
+    fn unknown_or_missing_tag_returns_an_error() {
+        let tagged_type = shape_type();
+
+        assert_eq!(
+            tagged_type.validate(&json!({ "kind": "triangle" })),
+            Err(TaggedTypeError::UnknownTag(
+                json!("triangle"),
+                vec!["circle".to_string(), "square".to_string()]
+            ))
+        );
+        assert_eq!(
+            tagged_type.validate(&json!({ "kind": 10 })),
+            Err(TaggedTypeError::UnknownTag(
+                json!(10),
+                vec!["circle".to_string(), "square".to_string()]
+            ))
+        );
+        assert_eq!(
+            tagged_type.validate(&json!({ "radius": 10 })),
+            Err(TaggedTypeError::MissingDiscriminator("kind".to_string()))
+        );
+        assert_eq!(tagged_type.validate(&json!([])), Err(TaggedTypeError::NotAnObject));
+    }
+}
@@ -1,32 +1,84 @@
 use std::fmt::{Display, Formatter};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Map, Value};
 use thiserror::Error;
 use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::{AdvancedType, AdvancedTypeValidationError};
+use crate::traits::reporting_validator::ReportingValidator;
 use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
 
 #[derive(Debug, PartialEq, Error)]
 pub enum TupleError {
     #[error("Expected an array, but got something else")]
     NotAnArray,
 
-    #[error("Expected an array with {0} items, but got an array with {1} items")]
-    IncorrectLength(usize, usize),
+    #[error("Expected an array with {} items, but got an array with {0} items", display_range(*.1, *.2))]
+    IncorrectLength(usize, usize, Option<usize>),
 
     #[error("{0}")]
     SchemaTypeValidationError(Box<SchemaTypeValidationError>),
 }
 
+impl ErrorCode for TupleError {
+    fn code(&self) -> &'static str {
+        match self {
+            TupleError::NotAnArray => "tuple.notAnArray",
+            TupleError::IncorrectLength(_, _, _) => "tuple.incorrectLength",
+            TupleError::SchemaTypeValidationError(error) => error.code(),
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            TupleError::NotAnArray => Map::new(),
+            TupleError::IncorrectLength(length, min, max) => to_params(json!({
+                "length": length,
+                "min": min,
+                "max": max,
+                "expected": display_range(*min, *max),
+            })),
+            TupleError::SchemaTypeValidationError(error) => error.params(),
+        }
+    }
+
+    fn custom_message(&self) -> Option<&str> {
+        match self {
+            TupleError::SchemaTypeValidationError(error) => error.custom_message(),
+            _ => None,
+        }
+    }
+}
+
+serialize_error_code!(TupleError);
+
+fn display_range(min: usize, max: Option<usize>) -> String {
+    match max {
+        Some(max) if max == min => min.to_string(),
+        Some(max) => format!("{} to {}", min, max),
+        None => format!("at least {}", min),
+    }
+}
+
 impl From<SchemaTypeValidationError> for TupleError {
     fn from(value: SchemaTypeValidationError) -> Self {
         TupleError::SchemaTypeValidationError(Box::new(value))
     }
 }
 
-/// Checks an array for the exact number of items and types.
+/// Checks an array for the number of items and the type of each item. Trailing items that are an
+/// [OptionalType] can be omitted, and any items after the listed items are checked against
+/// [TupleType::rest]. Without a rest schema, the array cannot have more items than are listed.
+///
+/// [OptionalType]: crate::schema_type::advanced_type::optional_type::OptionalType
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TupleType {
     pub items: Vec<SchemaType>,
+
+    /// If set, any number of items can follow the listed items, which should all match this schema.
+    pub rest: Option<Box<SchemaType>>,
 }
 
 impl Display for TupleType {
@@ -38,31 +90,119 @@ impl Display for TupleType {
             .collect::<Vec<String>>()
             .join(", ");
 
-        write!(f, "tuple with items: {}", items)
+        write!(f, "tuple with items: {}", items)?;
+
+        if let Some(rest) = &self.rest {
+            write!(f, ", ...")?;
+            Display::fmt(rest, f)?;
+        }
+
+        Ok(())
     }
 }
 
 impl Validator for TupleType {
     type E = TupleError;
 
-    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
         let Value::Array(value_items) = value else {
             return Err(TupleError::NotAnArray);
         };
 
-        if value_items.len() != self.items.len() {
-            return Err(TupleError::IncorrectLength(value_items.len(), self.items.len()));
+        TupleType::validate_length(&self.items, self.rest.is_some(), value_items.len())?;
+
+        for (schema, item_value) in self.items.iter().zip(value_items) {
+            schema.validate_in(item_value, scope.nested())?;
+        }
+
+        if let Some(rest) = &self.rest {
+            for item_value in value_items.iter().skip(self.items.len()) {
+                rest.validate_in(item_value, scope.nested())?;
+            }
         }
 
-        for (i, schema) in self.items.iter().enumerate() {
-            let item_value = value.get(i)
-                .expect("Both vecs should be the same size, so this should never be None");
+        Ok(())
+    }
+}
+
+impl TupleType {
+    /// Returns the minimum and maximum number of items for the given item schemas. All trailing
+    /// optional items can be omitted, and there is no maximum if there is a rest schema.
+    pub(crate) fn length_range(items: &[SchemaType], has_rest: bool) -> (usize, Option<usize>) {
+        let optional_items = items.iter()
+            .rev()
+            .take_while(|schema| matches!(schema.as_advanced(), Some(AdvancedType::Optional(_))))
+            .count();
+
+        let max = if has_rest { None } else { Some(items.len()) };
+
+        (items.len() - optional_items, max)
+    }
+
+    /// Checks the number of items against [TupleType::length_range]. This is shared with the
+    /// [SchemaType::Tuple] shorthand, which never has a rest schema.
+    pub(crate) fn validate_length(
+        items: &[SchemaType],
+        has_rest: bool,
+        length: usize,
+    ) -> Result<(), TupleError> {
+        let (min, max) = TupleType::length_range(items, has_rest);
+
+        TupleType::check_length(min, max, length)
+    }
 
-            schema.validate(item_value)?;
+    /// Checks the number of items against a range from [TupleType::length_range]. This is shared
+    /// with the [CompiledSchema], which computes the range once.
+    ///
+    /// [CompiledSchema]: crate::compiled_schema::CompiledSchema
+    pub(crate) fn check_length(min: usize, max: Option<usize>, length: usize) -> Result<(), TupleError> {
+        if length < min || max.is_some_and(|max| length > max) {
+            return Err(TupleError::IncorrectLength(length, min, max));
         }
 
         Ok(())
     }
+
+    /// Reports the errors for every item that is present in the array. This is shared with the
+    /// [SchemaType::Tuple] shorthand, which stores the item schemas at a different location.
+    pub(crate) fn report_items(
+        schemas: &[SchemaType],
+        value_items: &[Value],
+        context: &mut ValidationContext,
+    ) {
+        for (i, (schema, item_value)) in schemas.iter().zip(value_items).enumerate() {
+            context.enter_schema(i, |context| {
+                context.enter_instance(i, |context| schema.report(item_value, context));
+            });
+        }
+    }
+}
+
+impl ReportingValidator for TupleType {
+    /// Also checks the items that are present when the length is incorrect, so all problems with
+    /// the provided items are reported at once.
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        let Value::Array(value_items) = value else {
+            context.report(AdvancedTypeValidationError::TupleError(TupleError::NotAnArray));
+            return;
+        };
+
+        if let Err(error) = TupleType::validate_length(&self.items, self.rest.is_some(), value_items.len()) {
+            context.report(AdvancedTypeValidationError::TupleError(error));
+        }
+
+        context.enter_schema("items", |context| {
+            TupleType::report_items(&self.items, value_items, context);
+        });
+
+        if let Some(rest) = &self.rest {
+            context.enter_schema("rest", |context| {
+                for (i, item_value) in value_items.iter().enumerate().skip(self.items.len()) {
+                    context.enter_instance(i, |context| rest.report(item_value, context));
+                }
+            });
+        }
+    }
 }
 
 impl<const U: usize> From<[SchemaType; U]> for TupleType {
@@ -70,6 +210,7 @@ impl<const U: usize> From<[SchemaType; U]> for TupleType {
         TupleType {
             items: value.into_iter()
                 .collect(),
+            rest: None,
         }
     }
 }
@@ -77,7 +218,8 @@ impl<const U: usize> From<[SchemaType; U]> for TupleType {
 impl From<Vec<SchemaType>> for TupleType {
     fn from(value: Vec<SchemaType>) -> Self {
         TupleType {
-            items: value
+            items: value,
+            rest: None,
         }
     }
 }
@@ -88,6 +230,8 @@ mod tests {
     use crate::schema_type::advanced_type::tuple_type::{TupleError, TupleType};
     use crate::schema_type::basic_type::{BasicType, BasicTypeValidationError};
     use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+    use crate::schema_type::advanced_type::optional_type::OptionalType;
+    use crate::traits::reporting_validator::ReportingValidator;
     use crate::traits::validator::Validator;
 
     #[test]
@@ -124,7 +268,50 @@ mod tests {
             BasicType::Number.into(),
         ]);
 
-        assert_eq!(fixed_array_type.validate(&json!([""])), Err(TupleError::IncorrectLength(1, 2)));
-        assert_eq!(fixed_array_type.validate(&json!(["", 10, ""])), Err(TupleError::IncorrectLength(3, 2)));
+        assert_eq!(fixed_array_type.validate(&json!([""])), Err(TupleError::IncorrectLength(1, 2, Some(2))));
+        assert_eq!(fixed_array_type.validate(&json!(["", 10, ""])), Err(TupleError::IncorrectLength(3, 2, Some(2))));
+    }
+
+    #[test]
+    fn trailing_optional_items_can_be_omitted() {
+        let coordinate_type = TupleType::from([
+            BasicType::Number.into(),
+            BasicType::Number.into(),
+            OptionalType::from(SchemaType::Basic(BasicType::Number)).into(),
+        ]);
+
+        assert_eq!(coordinate_type.validate(&json!([52.1, 5.1])), Ok(()));
+        assert_eq!(coordinate_type.validate(&json!([52.1, 5.1, 10])), Ok(()));
+        assert!(coordinate_type.validate(&json!([52.1, 5.1, "10"])).is_err());
+        assert_eq!(
+            coordinate_type.validate(&json!([52.1])),
+            Err(TupleError::IncorrectLength(1, 2, Some(3)))
+        );
+        assert_eq!(
+            coordinate_type.validate(&json!([52.1, 5.1, 10, 0])),
+            Err(TupleError::IncorrectLength(4, 2, Some(3)))
+        );
+        assert_eq!(
+            TupleError::IncorrectLength(1, 2, Some(3)).to_string(),
+            "Expected an array with 2 to 3 items, but got an array with 1 items"
+        );
+    }
+
+    #[test]
+    fn rest_items_are_validated() {
+        let command_type = TupleType {
+            rest: Some(Box::new(BasicType::String.into())),
+            ..TupleType::from([BasicType::FilledString.into()])
+        };
+
+        assert_eq!(command_type.validate(&json!(["ls"])), Ok(()));
+        assert_eq!(command_type.validate(&json!(["ls", "-l", "-a"])), Ok(()));
+        assert_eq!(command_type.validate(&json!([])), Err(TupleError::IncorrectLength(0, 1, None)));
+        assert!(command_type.validate(&json!(["ls", "-l", 10])).is_err());
+
+        let report = command_type.validate_all(&json!(["ls", "-l", 10]));
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].instance_path.to_json_pointer(), "/2");
+        assert_eq!(report.errors[0].schema_path.to_json_pointer(), "/rest");
     }
 }
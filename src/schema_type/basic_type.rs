@@ -1,11 +1,15 @@
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::string_format::{StringFormat, StringFormatError};
+use crate::traits::reporting_validator::ReportingValidator;
 use crate::traits::validator::Validator;
 use serde::{Deserialize, Serialize};
 use serde_email::is_valid_email;
-use serde_json::{Number, Value};
+use serde_json::{json, Map, Number, Value};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use thiserror::Error;
 use uuid::Uuid;
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
 
 #[derive(Debug, Clone, PartialEq, Error)]
 pub enum BasicTypeValidationError {
@@ -42,6 +46,20 @@ pub enum BasicTypeValidationError {
     #[error("Expected a i64, but got '{0}'")]
     NotAI64(Number),
 
+    #[cfg(feature = "arbitrary_precision")]
+    #[error("Expected a u128, but got '{0}'")]
+    NotAU128(Number),
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[error("Expected a i128, but got '{0}'")]
+    NotAI128(Number),
+
+    #[error("Expected a f32, but got '{0}'")]
+    NotAF32(Number),
+
+    #[error("Expected an integer, but got '{0}'")]
+    NotAnInteger(Number),
+
     #[error("Incorrect type provided. Expected '{0}' but got '{1}'")]
     IncorrectType(BasicType, Value),
 
@@ -50,10 +68,71 @@ pub enum BasicTypeValidationError {
 
     #[error("Expected an email, but got '{0}'")]
     IncorrectEmail(String),
+
+    #[error(transparent)]
+    IncorrectFormat(#[from] StringFormatError),
+}
+
+impl ErrorCode for BasicTypeValidationError {
+    fn code(&self) -> &'static str {
+        match self {
+            BasicTypeValidationError::EmptyString => "basic.emptyString",
+            BasicTypeValidationError::NotAPositiveNumber(_) => "basic.notPositive",
+            BasicTypeValidationError::NotANegativeNumber(_) => "basic.notNegative",
+            BasicTypeValidationError::NotAU8(_) => "basic.notU8",
+            BasicTypeValidationError::NotAU16(_) => "basic.notU16",
+            BasicTypeValidationError::NotAU32(_) => "basic.notU32",
+            BasicTypeValidationError::NotAU64(_) => "basic.notU64",
+            BasicTypeValidationError::NotAI8(_) => "basic.notI8",
+            BasicTypeValidationError::NotAI16(_) => "basic.notI16",
+            BasicTypeValidationError::NotAI32(_) => "basic.notI32",
+            BasicTypeValidationError::NotAI64(_) => "basic.notI64",
+            #[cfg(feature = "arbitrary_precision")]
+            BasicTypeValidationError::NotAU128(_) => "basic.notU128",
+            #[cfg(feature = "arbitrary_precision")]
+            BasicTypeValidationError::NotAI128(_) => "basic.notI128",
+            BasicTypeValidationError::NotAF32(_) => "basic.notF32",
+            BasicTypeValidationError::NotAnInteger(_) => "basic.notInteger",
+            BasicTypeValidationError::IncorrectType(_, _) => "basic.incorrectType",
+            BasicTypeValidationError::IncorrectUuid(_) => "basic.incorrectUuid",
+            BasicTypeValidationError::IncorrectEmail(_) => "basic.incorrectEmail",
+            BasicTypeValidationError::IncorrectFormat(error) => error.code(),
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        match self {
+            BasicTypeValidationError::EmptyString => Map::new(),
+            BasicTypeValidationError::NotAPositiveNumber(number)
+            | BasicTypeValidationError::NotANegativeNumber(number)
+            | BasicTypeValidationError::NotAU8(number)
+            | BasicTypeValidationError::NotAU16(number)
+            | BasicTypeValidationError::NotAU32(number)
+            | BasicTypeValidationError::NotAU64(number)
+            | BasicTypeValidationError::NotAI8(number)
+            | BasicTypeValidationError::NotAI16(number)
+            | BasicTypeValidationError::NotAI32(number)
+            | BasicTypeValidationError::NotAI64(number)
+            | BasicTypeValidationError::NotAF32(number)
+            | BasicTypeValidationError::NotAnInteger(number) => to_params(json!({ "value": number })),
+            #[cfg(feature = "arbitrary_precision")]
+            BasicTypeValidationError::NotAU128(number)
+            | BasicTypeValidationError::NotAI128(number) => to_params(json!({ "value": number })),
+            BasicTypeValidationError::IncorrectType(expected, value) => {
+                to_params(json!({ "expected": expected, "value": value }))
+            }
+            BasicTypeValidationError::IncorrectUuid(value)
+            | BasicTypeValidationError::IncorrectEmail(value) => to_params(json!({ "value": value })),
+            BasicTypeValidationError::IncorrectFormat(error) => error.params(),
+        }
+    }
 }
 
+serialize_error_code!(BasicTypeValidationError);
+
 /// Basic types don't have any additional configuration and only check the variant of [Value] and
-/// might do a bit of extra validation in the case of [BasicType::Uuid] and [BasicType::Email].
+/// might do a bit of extra validation in the case of [BasicType::Uuid], [BasicType::Email] and the
+/// other string formats.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum BasicType {
@@ -102,6 +181,23 @@ pub enum BasicType {
     /// Matches if the value is between [i64::MIN] and [i64::MAX] (inclusive)
     I64,
 
+    /// Matches if the value is between 0 and [u128::MAX] (inclusive). Numbers outside of the
+    /// range of [u64] can only be checked if serde_json's `arbitrary_precision` feature is enabled,
+    /// so this is only available with the `arbitrary_precision` feature of this crate.
+    #[cfg(feature = "arbitrary_precision")]
+    U128,
+
+    /// Matches if the value is between [i128::MIN] and [i128::MAX] (inclusive). Only available
+    /// with the `arbitrary_precision` feature, see [BasicType::U128].
+    #[cfg(feature = "arbitrary_precision")]
+    I128,
+
+    /// Matches if the value is a finite number that fits in a [f32] without overflowing.
+    F32,
+
+    /// Matches any whole number, regardless of its size.
+    Integer,
+
     /// Checks if the value is `null`.
     Null,
 
@@ -120,6 +216,36 @@ pub enum BasicType {
 
     /// Matches if the value is a string and is formatted as an email address.
     Email,
+
+    /// Matches if the value is a string and is formatted as a date, see [StringFormat::Date].
+    Date,
+
+    /// Matches if the value is a string and is formatted as a date with a time, see
+    /// [StringFormat::DateTime].
+    DateTime,
+
+    /// Matches if the value is a string and is formatted as a time, see [StringFormat::Time].
+    Time,
+
+    /// Matches if the value is a string and is formatted as a duration, see
+    /// [StringFormat::Duration].
+    Duration,
+
+    /// Matches if the value is a string and is formatted as an absolute URI, see [StringFormat::Uri].
+    Uri,
+
+    /// Matches if the value is a string and is formatted as an IPv4 or IPv6 address.
+    Ip,
+
+    /// Matches if the value is a string and is formatted as an IPv4 address.
+    Ipv4,
+
+    /// Matches if the value is a string and is formatted as an IPv6 address.
+    Ipv6,
+
+    /// Matches if the value is a string and is formatted as a hostname, see
+    /// [StringFormat::Hostname].
+    Hostname,
 }
 
 impl BasicType {
@@ -185,10 +311,99 @@ impl BasicType {
             BasicType::I8 => {
                 let value = number.as_i64()
                     .ok_or(BasicTypeValidationError::NotAI8(number.clone()))?;
+
+                if value < i64::from(i8::MIN) || value > i64::from(i8::MAX) {
+                    return Err(BasicTypeValidationError::NotAI8(number.clone()));
+                }
+
+                Ok(())
             },
-            BasicType::I16 => todo!(),
-            BasicType::I32 => todo!(),
-            BasicType::I64 => todo!(),
+            BasicType::I16 => {
+                let value = number.as_i64()
+                    .ok_or(BasicTypeValidationError::NotAI16(number.clone()))?;
+
+                if value < i64::from(i16::MIN) || value > i64::from(i16::MAX) {
+                    return Err(BasicTypeValidationError::NotAI16(number.clone()));
+                }
+
+                Ok(())
+            },
+            BasicType::I32 => {
+                let value = number.as_i64()
+                    .ok_or(BasicTypeValidationError::NotAI32(number.clone()))?;
+
+                if value < i64::from(i32::MIN) || value > i64::from(i32::MAX) {
+                    return Err(BasicTypeValidationError::NotAI32(number.clone()));
+                }
+
+                Ok(())
+            },
+            BasicType::I64 => {
+                if !number.is_i64() {
+                    return Err(BasicTypeValidationError::NotAI64(number.clone()));
+                }
+
+                Ok(())
+            },
+            // With `arbitrary_precision` the number is formatted exactly like it was provided, so
+            // parsing the string representation is lossless.
+            #[cfg(feature = "arbitrary_precision")]
+            BasicType::U128 => {
+                number.to_string()
+                    .parse::<u128>()
+                    .map_err(|_| BasicTypeValidationError::NotAU128(number.clone()))?;
+
+                Ok(())
+            },
+            #[cfg(feature = "arbitrary_precision")]
+            BasicType::I128 => {
+                number.to_string()
+                    .parse::<i128>()
+                    .map_err(|_| BasicTypeValidationError::NotAI128(number.clone()))?;
+
+                Ok(())
+            },
+            BasicType::F32 => {
+                let value = number.as_f64()
+                    .ok_or(BasicTypeValidationError::NotAF32(number.clone()))?;
+
+                // Values that are slightly larger than f32::MAX still round to f32::MAX, anything
+                // larger than that overflows to infinity.
+                if !value.is_finite() || !(value as f32).is_finite() {
+                    return Err(BasicTypeValidationError::NotAF32(number.clone()));
+                }
+
+                Ok(())
+            },
+            BasicType::Integer => {
+                if number.is_i64() || number.is_u64() {
+                    return Ok(());
+                }
+
+                let value = number.as_f64()
+                    .ok_or(BasicTypeValidationError::NotAnInteger(number.clone()))?;
+
+                if !value.is_finite() || value.fract() != 0_f64 {
+                    return Err(BasicTypeValidationError::NotAnInteger(number.clone()));
+                }
+
+                Ok(())
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn string_format(&self) -> StringFormat {
+        match self {
+            BasicType::Date => StringFormat::Date,
+            BasicType::DateTime => StringFormat::DateTime,
+            BasicType::Time => StringFormat::Time,
+            BasicType::Duration => StringFormat::Duration,
+            BasicType::Uri => StringFormat::Uri,
+            BasicType::Ip => StringFormat::Ip,
+            BasicType::Ipv4 => StringFormat::Ipv4,
+            BasicType::Ipv6 => StringFormat::Ipv6,
+            BasicType::Hostname => StringFormat::Hostname,
             _ => unreachable!(),
         }
     }
@@ -217,6 +432,21 @@ impl Display for BasicType {
             BasicType::I16 => "i16",
             BasicType::I32 => "i32",
             BasicType::I64 => "i64",
+            #[cfg(feature = "arbitrary_precision")]
+            BasicType::U128 => "u128",
+            #[cfg(feature = "arbitrary_precision")]
+            BasicType::I128 => "i128",
+            BasicType::F32 => "f32",
+            BasicType::Integer => "integer",
+            BasicType::Date
+            | BasicType::DateTime
+            | BasicType::Time
+            | BasicType::Duration
+            | BasicType::Uri
+            | BasicType::Ip
+            | BasicType::Ipv4
+            | BasicType::Ipv6
+            | BasicType::Hostname => return Display::fmt(&self.string_format(), f),
         };
 
         write!(f, "{}", slice)
@@ -242,9 +472,13 @@ impl Validator for BasicType {
                 | BasicType::I8
                 | BasicType::I16
                 | BasicType::I32
-                | BasicType::I64,
+                | BasicType::I64
+                | BasicType::F32
+                | BasicType::Integer,
                 Value::Number(number)
             ) => self.validate_number(number),
+            #[cfg(feature = "arbitrary_precision")]
+            (BasicType::U128 | BasicType::I128, Value::Number(number)) => self.validate_number(number),
             (BasicType::String, Value::String(_)) => Ok(()),
             (BasicType::FilledString, Value::String(string)) => {
                 if string.is_empty() {
@@ -270,6 +504,18 @@ impl Validator for BasicType {
 
                 Ok(())
             }
+            (
+                BasicType::Date
+                | BasicType::DateTime
+                | BasicType::Time
+                | BasicType::Duration
+                | BasicType::Uri
+                | BasicType::Ip
+                | BasicType::Ipv4
+                | BasicType::Ipv6
+                | BasicType::Hostname,
+                Value::String(string_value)
+            ) => Ok(self.string_format().validate_str(string_value)?),
             (_, _) => Err(BasicTypeValidationError::IncorrectType(
                 self.clone(),
                 value.clone(),
@@ -278,9 +524,16 @@ impl Validator for BasicType {
     }
 }
 
+impl ReportingValidator for BasicType {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        context.report_result(self.validate(value));
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::schema_type::basic_type::BasicType;
+    use crate::schema_type::basic_type::{BasicType, BasicTypeValidationError};
+    use crate::schema_type::string_format::StringFormatError;
     use crate::traits::validator::Validator;
     use serde_json::{json, Number, Value};
 
@@ -373,6 +626,10 @@ mod tests {
             .validate(&json!("f1df9904-6f6b-4157-8a82-b1a566a50ec2"))
             .is_ok());
 
+        assert_eq!(
+            BasicType::Uuid.validate(&json!("not-a-uuid")),
+            Err(BasicTypeValidationError::IncorrectUuid("not-a-uuid".to_string()))
+        );
         assert!(BasicType::Uuid.validate(&json!("")).is_err());
         assert!(BasicType::Uuid.validate(&json!(null)).is_err());
         assert!(BasicType::Uuid.validate(&json!(true)).is_err());
@@ -394,6 +651,10 @@ mod tests {
         assert!(BasicType::Email
             .validate(&json!("f1df9904-6f6b-4157-8a82-b1a566a50ec2"))
             .is_err());
+        assert_eq!(
+            BasicType::Email.validate(&json!("alice")),
+            Err(BasicTypeValidationError::IncorrectEmail("alice".to_string()))
+        );
         assert!(BasicType::Email.validate(&json!("")).is_err());
         assert!(BasicType::Email.validate(&json!(null)).is_err());
         assert!(BasicType::Email.validate(&json!(true)).is_err());
@@ -403,6 +664,34 @@ mod tests {
         assert!(BasicType::Email.validate(&json!({})).is_err());
     }
 
+    #[test]
+    fn basic_string_formats_are_validated_correctly() {
+        assert!(BasicType::Date.validate(&json!("2023-10-12")).is_ok());
+        assert!(BasicType::DateTime.validate(&json!("2023-10-12T14:30:00Z")).is_ok());
+        assert!(BasicType::Time.validate(&json!("14:30:00Z")).is_ok());
+        assert!(BasicType::Duration.validate(&json!("PT15M")).is_ok());
+        assert!(BasicType::Uri.validate(&json!("https://example.com")).is_ok());
+        assert!(BasicType::Ip.validate(&json!("10.0.0.1")).is_ok());
+        assert!(BasicType::Ipv4.validate(&json!("10.0.0.1")).is_ok());
+        assert!(BasicType::Ipv6.validate(&json!("::1")).is_ok());
+        assert!(BasicType::Hostname.validate(&json!("example.com")).is_ok());
+
+        assert_eq!(
+            BasicType::DateTime.validate(&json!("2023-10-12")),
+            Err(BasicTypeValidationError::IncorrectFormat(
+                StringFormatError::IncorrectDateTime("2023-10-12".to_string())
+            ))
+        );
+        assert_eq!(
+            BasicType::Hostname.validate(&json!(10)),
+            Err(BasicTypeValidationError::IncorrectType(BasicType::Hostname, json!(10)))
+        );
+        assert_eq!(
+            BasicType::Uri.validate(&json!("example.com")).unwrap_err().to_string(),
+            "Expected a URI, but got 'example.com'"
+        );
+    }
+
     #[test]
     fn positive_number_is_validated_correctly() {
         assert!(BasicType::PositiveNumber.validate(&json!("")).is_err());
@@ -502,7 +791,63 @@ mod tests {
         assert!(BasicType::I64.validate(&json!(9223372036854775807_i128)).is_ok());
         assert!(BasicType::I64.validate(&json!(-9223372036854775808_i128)).is_ok());
         assert!(BasicType::I64.validate(&json!(9223372036854775808_i128)).is_err());
-        assert!(BasicType::I64.validate(&json!(-9223372036854775809_i128)).is_err());
+        assert!(BasicType::I64.validate(&serde_json::from_str::<Value>("-9223372036854775809").unwrap()).is_err());
         assert!(BasicType::I64.validate(&json!(1.1)).is_err());
     }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn u128_is_validated_correctly() {
+        let max = serde_json::from_str::<Value>("340282366920938463463374607431768211455").unwrap();
+        let overflow = serde_json::from_str::<Value>("340282366920938463463374607431768211456").unwrap();
+
+        assert!(BasicType::U128.validate(&json!("")).is_err());
+        assert!(BasicType::U128.validate(&json!(0)).is_ok());
+        assert!(BasicType::U128.validate(&max).is_ok());
+        assert!(BasicType::U128.validate(&json!(-1)).is_err());
+        assert!(BasicType::U128.validate(&overflow).is_err());
+        assert!(BasicType::U128.validate(&json!(1.1)).is_err());
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn i128_is_validated_correctly() {
+        let max = serde_json::from_str::<Value>("170141183460469231731687303715884105727").unwrap();
+        let min = serde_json::from_str::<Value>("-170141183460469231731687303715884105728").unwrap();
+        let overflow = serde_json::from_str::<Value>("170141183460469231731687303715884105728").unwrap();
+        let underflow = serde_json::from_str::<Value>("-170141183460469231731687303715884105729").unwrap();
+
+        assert!(BasicType::I128.validate(&json!("")).is_err());
+        assert!(BasicType::I128.validate(&json!(0)).is_ok());
+        assert!(BasicType::I128.validate(&max).is_ok());
+        assert!(BasicType::I128.validate(&min).is_ok());
+        assert!(BasicType::I128.validate(&overflow).is_err());
+        assert!(BasicType::I128.validate(&underflow).is_err());
+        assert!(BasicType::I128.validate(&json!(1.1)).is_err());
+    }
+
+    #[test]
+    fn f32_is_validated_correctly() {
+        assert!(BasicType::F32.validate(&json!("")).is_err());
+        assert!(BasicType::F32.validate(&json!(0)).is_ok());
+        assert!(BasicType::F32.validate(&json!(1.1)).is_ok());
+        assert!(BasicType::F32.validate(&json!(f32::MAX)).is_ok());
+        assert!(BasicType::F32.validate(&json!(f32::MIN)).is_ok());
+        assert!(BasicType::F32.validate(&json!(3.5e38)).is_err());
+        assert!(BasicType::F32.validate(&json!(-3.5e38)).is_err());
+    }
+
+    #[test]
+    fn integer_is_validated_correctly() {
+        assert!(BasicType::Integer.validate(&json!("")).is_err());
+        assert!(BasicType::Integer.validate(&json!(0)).is_ok());
+        assert!(BasicType::Integer.validate(&json!(-10)).is_ok());
+        assert!(BasicType::Integer.validate(&json!(18446744073709551615_u64)).is_ok());
+        assert!(BasicType::Integer.validate(&json!(1e20)).is_ok());
+        assert!(BasicType::Integer.validate(&json!(1.1)).is_err());
+        assert_eq!(
+            BasicType::Integer.validate(&json!(-0.5)),
+            Err(BasicTypeValidationError::NotAnInteger(Number::from_f64(-0.5).unwrap()))
+        );
+    }
 }
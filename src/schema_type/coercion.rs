@@ -0,0 +1,483 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use serde_json::{Number, Value};
+use crate::report::validation_path::ValidationPath;
+use crate::schema::validation_scope::ValidationScope;
+use crate::schema_type::advanced_type::object_type::AdditionalProperties;
+use crate::schema_type::advanced_type::AdvancedType;
+use crate::schema_type::error_message::WithErrorMessage;
+use crate::schema_type::basic_type::BasicType;
+use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::shared::json_equals;
+use crate::traits::validator::Validator;
+
+/// A conversion that has been made by [SchemaType::validate_and_coerce].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coercion {
+    /// Location of the converted value.
+    pub path: ValidationPath,
+
+    /// The value before it was converted.
+    pub from: Value,
+
+    /// The value after it was converted.
+    pub to: Value,
+}
+
+impl SchemaType {
+    /// Validates input where every value might have been provided as a string, like values from
+    /// HTML forms and query strings. Before validating, values are converted in place where the
+    /// schema expects a different type:
+    ///
+    /// - Strings are parsed as a number where a number is expected, so `"42"` becomes `42`.
+    /// - `"true"` and `"false"` become a boolean where a boolean is expected.
+    /// - `""` and `"null"` become `null` where `null` is expected, including for an [OptionalType]
+    ///   that does not accept an empty string.
+    /// - A string, number or boolean is wrapped in an array where an array is expected, so a
+    ///   single value becomes a list with one item.
+    ///
+    /// Values that cannot be converted are left as is and result in the usual validation errors.
+    /// When validation fails, the conversions that have already been made are kept.
+    ///
+    /// References are resolved in the default [ValidationScope], which has no definitions, use
+    /// [SchemaType::validate_and_coerce_in] to provide them.
+    ///
+    /// [OptionalType]: crate::schema_type::advanced_type::optional_type::OptionalType
+    pub fn validate_and_coerce(&self, value: &mut Value) -> Result<Vec<Coercion>, Box<SchemaTypeValidationError>> {
+        self.validate_and_coerce_in(value, ValidationScope::default())
+    }
+
+    /// Converts and validates the value like [SchemaType::validate_and_coerce], resolving
+    /// references against the definitions of the provided scope.
+    pub fn validate_and_coerce_in(
+        &self,
+        value: &mut Value,
+        scope: ValidationScope,
+    ) -> Result<Vec<Coercion>, Box<SchemaTypeValidationError>> {
+        let mut coercions = vec![];
+        self.coerce_at(value, scope, &mut ValidationPath::new(), &mut coercions);
+        self.validate_in(value, scope).map_err(Box::new)?;
+
+        Ok(coercions)
+    }
+
+    fn coerce_at(
+        &self,
+        value: &mut Value,
+        scope: ValidationScope,
+        path: &mut ValidationPath,
+        coercions: &mut Vec<Coercion>,
+    ) {
+        match self {
+            SchemaType::Basic(basic_type) => coerce_basic(basic_type, value, path, coercions),
+            SchemaType::Field(field) => field.field_type().coerce_at(value, scope, path, coercions),
+            SchemaType::Array(item) => coerce_items(&[], Some(&item.0), value, scope, path, coercions),
+            SchemaType::Tuple(items) => coerce_items(items, None, value, scope, path, coercions),
+            SchemaType::Object(map) => coerce_object(map, None, value, scope, path, coercions),
+            SchemaType::WithErrorMessage(WithErrorMessage { kind: advanced_type, .. })
+            | SchemaType::Advanced(advanced_type) => match advanced_type {
+                AdvancedType::Number(_) => coerce_number(value, path, coercions),
+                AdvancedType::Array(array_type) => {
+                    coerce_items(&[], Some(&array_type.items), value, scope, path, coercions);
+                }
+                AdvancedType::Tuple(tuple_type) => {
+                    coerce_items(&tuple_type.items, tuple_type.rest.as_deref(), value, scope, path, coercions);
+                }
+                AdvancedType::Object(object_type) => {
+                    let additional_properties = match &object_type.additional_properties {
+                        Some(AdditionalProperties::Schema(schema)) => Some(&**schema),
+                        _ => None,
+                    };
+
+                    coerce_object(&object_type.object, additional_properties, value, scope, path, coercions);
+
+                    for (trigger, schema) in &object_type.dependent_schemas {
+                        if value.get(trigger).is_some() {
+                            schema.coerce_at(value, scope, path, coercions);
+                        }
+                    }
+                }
+                AdvancedType::Record(record_type) => {
+                    coerce_object(&HashMap::new(), Some(&record_type.values), value, scope, path, coercions);
+                }
+                AdvancedType::Optional(optional_type) => {
+                    if value.is_null() {
+                        return;
+                    }
+
+                    let is_empty = value.as_str().is_some_and(|string| string.is_empty() || string == "null");
+
+                    if is_empty && optional_type.kind.validate_in(value, scope).is_err() {
+                        replace(value, Value::Null, path, coercions);
+                        return;
+                    }
+
+                    optional_type.kind.coerce_at(value, scope, path, coercions);
+                }
+                AdvancedType::Const(const_type) => {
+                    coerce_to_allowed(std::slice::from_ref(&const_type.value), value, path, coercions);
+                }
+                AdvancedType::Enum(enum_type) => {
+                    coerce_to_allowed(&enum_type.values, value, path, coercions);
+                }
+                AdvancedType::AllOf(all_of_type) => {
+                    for variant in &all_of_type.variants {
+                        variant.coerce_at(value, scope, path, coercions);
+                    }
+                }
+                AdvancedType::AnyOf(any_of_type) => {
+                    coerce_first_match(&any_of_type.variants, value, scope, path, coercions);
+                }
+                AdvancedType::OneOf(one_of_type) => {
+                    coerce_first_match(&one_of_type.variants, value, scope, path, coercions);
+                }
+                AdvancedType::Tagged(tagged_type) => {
+                    if let Ok((_, variant)) = tagged_type.variant_for(value) {
+                        variant.coerce_at(value, scope, path, coercions);
+                    }
+                }
+                AdvancedType::If(if_type) => {
+                    // The condition is checked against the converted value, so a condition like
+                    // `true` also matches `"true"`.
+                    let mut candidate = value.clone();
+                    let mut candidate_coercions = vec![];
+                    if_type.condition.coerce_at(&mut candidate, scope, path, &mut candidate_coercions);
+
+                    let branch = if if_type.matches(&candidate, scope) {
+                        *value = candidate;
+                        coercions.extend(candidate_coercions);
+                        if_type.then.as_deref()
+                    } else {
+                        if_type.otherwise.as_deref()
+                    };
+
+                    if let Some(branch) = branch {
+                        branch.coerce_at(value, scope, path, coercions);
+                    }
+                }
+                AdvancedType::Ref(ref_type) => {
+                    if let Ok((definition, scope)) = scope.resolve(&ref_type.name) {
+                        definition.coerce_at(value, scope, path, coercions);
+                    }
+                }
+                AdvancedType::String(_)
+                | AdvancedType::Not(_)
+                | AdvancedType::Custom(_) => {}
+            },
+        }
+    }
+}
+
+/// Replaces the value and records the coercion.
+fn replace(value: &mut Value, to: Value, path: &ValidationPath, coercions: &mut Vec<Coercion>) {
+    let from = std::mem::replace(value, to.clone());
+
+    coercions.push(Coercion {
+        path: path.clone(),
+        from,
+        to,
+    });
+}
+
+fn coerce_basic(basic_type: &BasicType, value: &mut Value, path: &ValidationPath, coercions: &mut Vec<Coercion>) {
+    match basic_type {
+        BasicType::Boolean => {
+            let to = match value.as_str() {
+                Some("true") => true,
+                Some("false") => false,
+                _ => return,
+            };
+
+            replace(value, Value::Bool(to), path, coercions);
+        }
+        BasicType::Null if value.as_str().is_some_and(|string| string.is_empty() || string == "null") => {
+            replace(value, Value::Null, path, coercions);
+        }
+        BasicType::Array if matches!(value, Value::String(_) | Value::Number(_) | Value::Bool(_)) => {
+            let item = value.clone();
+            replace(value, Value::Array(vec![item]), path, coercions);
+        }
+        BasicType::Number
+        | BasicType::PositiveNumber
+        | BasicType::NegativeNumber
+        | BasicType::U8
+        | BasicType::U16
+        | BasicType::U32
+        | BasicType::U64
+        | BasicType::I8
+        | BasicType::I16
+        | BasicType::I32
+        | BasicType::I64
+        | BasicType::F32
+        | BasicType::Integer => coerce_number(value, path, coercions),
+        #[cfg(feature = "arbitrary_precision")]
+        BasicType::U128 | BasicType::I128 => coerce_number(value, path, coercions),
+        _ => {}
+    }
+}
+
+/// Parses a string using the JSON number syntax, so values like `" 1"`, `"+1"` or `"0x10"` are
+/// not converted.
+fn coerce_number(value: &mut Value, path: &ValidationPath, coercions: &mut Vec<Coercion>) {
+    let Some(number) = value.as_str().and_then(|string| Number::from_str(string).ok()) else {
+        return;
+    };
+
+    replace(value, Value::Number(number), path, coercions);
+}
+
+/// Converts a string to one of the allowed values if the string is the JSON representation of
+/// that value, so `"1"` matches an allowed `1` and `"true"` matches an allowed `true`.
+fn coerce_to_allowed(allowed: &[Value], value: &mut Value, path: &ValidationPath, coercions: &mut Vec<Coercion>) {
+    let Some(string) = value.as_str() else {
+        return;
+    };
+
+    if allowed.iter().any(|allowed| json_equals(allowed, value)) {
+        return;
+    }
+
+    let Ok(parsed) = serde_json::from_str::<Value>(string) else {
+        return;
+    };
+
+    if let Some(to) = allowed.iter().find(|allowed| json_equals(allowed, &parsed)) {
+        replace(value, to.clone(), path, coercions);
+    }
+}
+
+fn coerce_object(
+    map: &HashMap<String, SchemaType>,
+    additional_properties: Option<&SchemaType>,
+    value: &mut Value,
+    scope: ValidationScope,
+    path: &mut ValidationPath,
+    coercions: &mut Vec<Coercion>,
+) {
+    let Value::Object(target_map) = value else {
+        return;
+    };
+
+    for (key, item_value) in target_map.iter_mut() {
+        let Some(schema) = map.get(key).or(additional_properties) else {
+            continue;
+        };
+
+        path.push(key.into());
+        schema.coerce_at(item_value, scope, path, coercions);
+        path.pop();
+    }
+}
+
+/// Wraps a single value in an array and converts the items. Items at a position in `items` use
+/// that schema, and any other items use `rest`.
+fn coerce_items(
+    items: &[SchemaType],
+    rest: Option<&SchemaType>,
+    value: &mut Value,
+    scope: ValidationScope,
+    path: &mut ValidationPath,
+    coercions: &mut Vec<Coercion>,
+) {
+    if matches!(value, Value::String(_) | Value::Number(_) | Value::Bool(_)) {
+        let item = value.clone();
+        replace(value, Value::Array(vec![item]), path, coercions);
+    }
+
+    let Value::Array(value_items) = value else {
+        return;
+    };
+
+    for (i, item_value) in value_items.iter_mut().enumerate() {
+        let Some(schema) = items.get(i).or(rest) else {
+            continue;
+        };
+
+        path.push(i.into());
+        schema.coerce_at(item_value, scope, path, coercions);
+        path.pop();
+    }
+}
+
+/// Uses the first variant that matches once converted. Nothing is converted if the value already
+/// matches one of the variants.
+fn coerce_first_match(
+    variants: &[SchemaType],
+    value: &mut Value,
+    scope: ValidationScope,
+    path: &mut ValidationPath,
+    coercions: &mut Vec<Coercion>,
+) {
+    if variants.iter().any(|variant| variant.validate_in(value, scope).is_ok()) {
+        return;
+    }
+
+    for variant in variants {
+        let mut candidate = value.clone();
+        let mut candidate_coercions = vec![];
+        variant.coerce_at(&mut candidate, scope, path, &mut candidate_coercions);
+
+        if variant.validate_in(&candidate, scope).is_ok() {
+            *value = candidate;
+            coercions.extend(candidate_coercions);
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use crate::schema_type::basic_type::{BasicType, BasicTypeValidationError};
+    use crate::schema_type::coercion::Coercion;
+    use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+
+    fn parse_schema_type(value: Value) -> SchemaType {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn strings_are_converted_to_the_expected_type() {
+        let schema_type = parse_schema_type(json!({
+            "age": "u8",
+            "price": { "$": "number", "minimum": 0 },
+            "subscribed": "boolean",
+            "deletedAt": "null",
+            "name": "string",
+        }));
+
+        let mut value = json!({
+            "age": "42",
+            "price": "9.95",
+            "subscribed": "true",
+            "deletedAt": "",
+            "name": "10",
+        });
+
+        let coercions = schema_type.validate_and_coerce(&mut value).unwrap();
+
+        assert_eq!(value, json!({
+            "age": 42,
+            "price": 9.95,
+            "subscribed": true,
+            "deletedAt": null,
+            "name": "10",
+        }));
+
+        let mut paths = coercions.iter()
+            .map(|coercion| coercion.path.to_json_pointer())
+            .collect::<Vec<String>>();
+
+        paths.sort();
+        assert_eq!(paths, vec!["/age", "/deletedAt", "/price", "/subscribed"]);
+    }
+
+    #[test]
+    fn coercion_is_recorded() {
+        let mut value = json!("true");
+
+        assert_eq!(
+            SchemaType::Basic(BasicType::Boolean).validate_and_coerce(&mut value),
+            Ok(vec![Coercion {
+                path: Default::default(),
+                from: json!("true"),
+                to: json!(true),
+            }])
+        );
+    }
+
+    #[test]
+    fn single_values_are_wrapped_in_an_array() {
+        let schema_type = parse_schema_type(json!({
+            "tags": ["string"],
+            "ids": { "$": "array", "items": "u32" },
+        }));
+
+        let mut value = json!({ "tags": "news", "ids": "7" });
+        schema_type.validate_and_coerce(&mut value).unwrap();
+
+        assert_eq!(value, json!({ "tags": ["news"], "ids": [7] }));
+    }
+
+    #[test]
+    fn null_and_objects_are_not_wrapped_in_an_array() {
+        let schema_type = parse_schema_type(json!({
+            "tags": { "$": "optional", "type": ["string"] },
+        }));
+
+        let mut value = json!({ "tags": null });
+        assert_eq!(schema_type.validate_and_coerce(&mut value), Ok(vec![]));
+        assert_eq!(value, json!({ "tags": null }));
+
+        let mut value = json!({ "tags": { "name": "news" } });
+        assert!(schema_type.validate_and_coerce(&mut value).is_err());
+        assert_eq!(value, json!({ "tags": { "name": "news" } }));
+
+        let schema_type = parse_schema_type(json!({ "tags": "array" }));
+
+        for tags in [json!(null), json!({ "a": 1 })] {
+            let mut value = json!({ "tags": tags });
+            assert!(schema_type.validate_and_coerce(&mut value).is_err());
+            assert_eq!(value, json!({ "tags": tags }));
+        }
+
+        let mut value = json!({ "tags": true });
+        schema_type.validate_and_coerce(&mut value).unwrap();
+        assert_eq!(value, json!({ "tags": [true] }));
+    }
+
+    #[test]
+    fn empty_optional_values_become_null() {
+        let schema_type = parse_schema_type(json!({
+            "age": { "$": "optional", "type": "u8" },
+            "nickname": { "$": "optional", "type": "string" },
+            "status": { "$": "enum", "values": [1, 2, null] },
+        }));
+
+        let mut value = json!({ "age": "", "nickname": "", "status": "2" });
+        schema_type.validate_and_coerce(&mut value).unwrap();
+
+        assert_eq!(value, json!({ "age": null, "nickname": "", "status": 2 }));
+    }
+
+    #[test]
+    fn first_matching_variant_is_used() {
+        let schema_type = parse_schema_type(json!({
+            "$": "anyOf",
+            "variants": ["boolean", "u8"],
+        }));
+
+        let mut value = json!("12");
+        schema_type.validate_and_coerce(&mut value).unwrap();
+        assert_eq!(value, json!(12));
+
+        let mut value = json!("false");
+        schema_type.validate_and_coerce(&mut value).unwrap();
+        assert_eq!(value, json!(false));
+    }
+
+    #[test]
+    fn impossible_conversions_return_the_usual_errors() {
+        let mut value = json!("300");
+
+        assert_eq!(
+            SchemaType::Basic(BasicType::U8).validate_and_coerce(&mut value),
+            Err(Box::new(SchemaTypeValidationError::BasicTypeValidationError(
+                BasicTypeValidationError::NotAU8(serde_json::from_str("300").unwrap())
+            )))
+        );
+
+        let mut value = json!("yes");
+
+        assert_eq!(
+            SchemaType::Basic(BasicType::Boolean).validate_and_coerce(&mut value),
+            Err(Box::new(SchemaTypeValidationError::BasicTypeValidationError(
+                BasicTypeValidationError::IncorrectType(BasicType::Boolean, json!("yes"))
+            )))
+        );
+
+        for input in [" 1", "+1", "0x10", "1,5"] {
+            assert!(SchemaType::Basic(BasicType::Number).validate_and_coerce(&mut json!(input)).is_err());
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use std::collections::HashMap;
+use serde_json::Value;
+use crate::report::validation_path::ValidationPath;
+use crate::schema::validation_scope::ValidationScope;
+use crate::schema_type::advanced_type::object_type::AdditionalProperties;
+use crate::schema_type::advanced_type::AdvancedType;
+use crate::schema_type::SchemaType;
+use crate::schema_type::error_message::WithErrorMessage;
+use crate::traits::validator::Validator;
+
+impl SchemaType {
+    /// Inserts the default value of every missing object key that has a default declared through a
+    /// [Field], at any depth. Returns the paths of the values that have been inserted. Defaults are
+    /// inserted as they are declared, but defaults for keys that are missing in an inserted default
+    /// are filled as well.
+    ///
+    /// For [AnyOfType] and [OneOfType], the defaults of the first variant that matches the value
+    /// are used. References are resolved in the default [ValidationScope], which has no
+    /// definitions, use [SchemaType::apply_defaults_in] to provide them.
+    ///
+    /// [Field]: crate::schema_type::field::Field
+    /// [AnyOfType]: crate::schema_type::advanced_type::any_of_type::AnyOfType
+    /// [OneOfType]: crate::schema_type::advanced_type::one_of_type::OneOfType
+    pub fn apply_defaults(&self, value: &mut Value) -> Vec<ValidationPath> {
+        self.apply_defaults_in(value, ValidationScope::default())
+    }
+
+    /// Inserts the defaults like [SchemaType::apply_defaults], resolving references against the
+    /// definitions of the provided scope.
+    pub fn apply_defaults_in(&self, value: &mut Value, scope: ValidationScope) -> Vec<ValidationPath> {
+        let mut filled = vec![];
+        self.apply_defaults_at(value, scope, &mut ValidationPath::new(), &mut filled);
+
+        // Keys of an object are visited in an arbitrary order, so the paths are sorted to keep the
+        // result stable.
+        filled.sort_by_key(|path| path.to_json_pointer());
+        filled
+    }
+
+    fn apply_defaults_at(
+        &self,
+        value: &mut Value,
+        scope: ValidationScope,
+        path: &mut ValidationPath,
+        filled: &mut Vec<ValidationPath>,
+    ) {
+        match self {
+            SchemaType::Basic(_) => {}
+            SchemaType::Field(field) => field.field_type().apply_defaults_at(value, scope, path, filled),
+            SchemaType::Array(item) => apply_item_defaults(&[], Some(&item.0), value, scope, path, filled),
+            SchemaType::Tuple(items) => apply_item_defaults(items, None, value, scope, path, filled),
+            SchemaType::Object(map) => apply_object_defaults(map, None, value, scope, path, filled),
+            SchemaType::WithErrorMessage(WithErrorMessage { kind: advanced_type, .. })
+            | SchemaType::Advanced(advanced_type) => match advanced_type {
+                AdvancedType::Object(object_type) => {
+                    let additional_properties = match &object_type.additional_properties {
+                        Some(AdditionalProperties::Schema(schema)) => Some(&**schema),
+                        _ => None,
+                    };
+
+                    apply_object_defaults(&object_type.object, additional_properties, value, scope, path, filled);
+
+                    for (trigger, schema) in &object_type.dependent_schemas {
+                        if value.get(trigger).is_some() {
+                            schema.apply_defaults_at(value, scope, path, filled);
+                        }
+                    }
+                }
+                AdvancedType::Record(record_type) => {
+                    apply_object_defaults(&HashMap::new(), Some(&record_type.values), value, scope, path, filled);
+                }
+                AdvancedType::Array(array_type) => {
+                    apply_item_defaults(&[], Some(&array_type.items), value, scope, path, filled);
+                }
+                AdvancedType::Tuple(tuple_type) => {
+                    let rest = tuple_type.rest.as_deref();
+                    apply_item_defaults(&tuple_type.items, rest, value, scope, path, filled);
+                }
+                AdvancedType::Optional(optional_type) => {
+                    if !value.is_null() {
+                        optional_type.kind.apply_defaults_at(value, scope, path, filled);
+                    }
+                }
+                AdvancedType::AllOf(all_of_type) => {
+                    for variant in &all_of_type.variants {
+                        variant.apply_defaults_at(value, scope, path, filled);
+                    }
+                }
+                AdvancedType::AnyOf(any_of_type) => {
+                    apply_first_match_defaults(&any_of_type.variants, value, scope, path, filled);
+                }
+                AdvancedType::OneOf(one_of_type) => {
+                    apply_first_match_defaults(&one_of_type.variants, value, scope, path, filled);
+                }
+                AdvancedType::Tagged(tagged_type) => {
+                    if let Ok((_, variant)) = tagged_type.variant_for(value) {
+                        variant.apply_defaults_at(value, scope, path, filled);
+                    }
+                }
+                AdvancedType::If(if_type) => {
+                    if let (Some(branch), _) = if_type.branch_for(value, scope) {
+                        branch.apply_defaults_at(value, scope, path, filled);
+                    }
+                }
+                AdvancedType::Ref(ref_type) => {
+                    if let Ok((definition, scope)) = scope.resolve(&ref_type.name) {
+                        definition.apply_defaults_at(value, scope, path, filled);
+                    }
+                }
+                AdvancedType::String(_)
+                | AdvancedType::Number(_)
+                | AdvancedType::Const(_)
+                | AdvancedType::Enum(_)
+                | AdvancedType::Not(_)
+                | AdvancedType::Custom(_) => {}
+            },
+        }
+    }
+
+    /// Returns the default that is declared for this schema, looking through optional types and
+    /// references.
+    fn declared_default(&self, scope: ValidationScope) -> Option<Value> {
+        if let SchemaType::Field(field) = self {
+            return field.default_value()
+                .cloned()
+                .or_else(|| field.field_type().declared_default(scope));
+        }
+
+        match self.as_advanced()? {
+            AdvancedType::Optional(optional_type) => optional_type.kind.declared_default(scope),
+            AdvancedType::Ref(ref_type) => {
+                let (definition, scope) = scope.resolve(&ref_type.name).ok()?;
+
+                definition.declared_default(scope)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn apply_object_defaults(
+    map: &HashMap<String, SchemaType>,
+    additional_properties: Option<&SchemaType>,
+    value: &mut Value,
+    scope: ValidationScope,
+    path: &mut ValidationPath,
+    filled: &mut Vec<ValidationPath>,
+) {
+    let Value::Object(target_map) = value else {
+        return;
+    };
+
+    for (key, schema) in map {
+        if !target_map.contains_key(key) {
+            let Some(default) = schema.declared_default(scope) else {
+                continue;
+            };
+
+            target_map.insert(key.to_string(), default);
+
+            let mut filled_path = path.clone();
+            filled_path.push(key.into());
+            filled.push(filled_path);
+        }
+    }
+
+    for (key, item_value) in target_map.iter_mut() {
+        let Some(schema) = map.get(key).or(additional_properties) else {
+            continue;
+        };
+
+        path.push(key.into());
+        schema.apply_defaults_at(item_value, scope, path, filled);
+        path.pop();
+    }
+}
+
+/// Applies the defaults to the items of an array. Items at a position in `items` use that schema,
+/// and any other items use `rest`.
+fn apply_item_defaults(
+    items: &[SchemaType],
+    rest: Option<&SchemaType>,
+    value: &mut Value,
+    scope: ValidationScope,
+    path: &mut ValidationPath,
+    filled: &mut Vec<ValidationPath>,
+) {
+    let Value::Array(value_items) = value else {
+        return;
+    };
+
+    for (i, item_value) in value_items.iter_mut().enumerate() {
+        let Some(schema) = items.get(i).or(rest) else {
+            continue;
+        };
+
+        path.push(i.into());
+        schema.apply_defaults_at(item_value, scope, path, filled);
+        path.pop();
+    }
+}
+
+fn apply_first_match_defaults(
+    variants: &[SchemaType],
+    value: &mut Value,
+    scope: ValidationScope,
+    path: &mut ValidationPath,
+    filled: &mut Vec<ValidationPath>,
+) {
+    if let Some(variant) = variants.iter().find(|variant| variant.validate_in(value, scope).is_ok()) {
+        variant.apply_defaults_at(value, scope, path, filled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use crate::schema::definitions::Definitions;
+    use crate::schema::validation_scope::ValidationScope;
+    use crate::schema_type::SchemaType;
+
+    fn parse_schema_type(value: Value) -> SchemaType {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn filled_pointers(schema_type: &SchemaType, value: &mut Value) -> Vec<String> {
+        filled_pointers_in(schema_type, value, ValidationScope::default())
+    }
+
+    fn filled_pointers_in(schema_type: &SchemaType, value: &mut Value, scope: ValidationScope) -> Vec<String> {
+        schema_type.apply_defaults_in(value, scope)
+            .iter()
+            .map(|path| path.to_json_pointer())
+            .collect()
+    }
+
+    #[test]
+    fn missing_keys_are_filled_at_any_depth() {
+        let schema_type = parse_schema_type(json!({
+            "name": "string",
+            "role": {
+                "?": { "$": "optional", "type": "string" },
+                "label": "Role",
+                "hint": null,
+                "default": "member",
+            },
+            "settings": {
+                "theme": {
+                    "?": { "$": "optional", "type": "string" },
+                    "label": "Theme",
+                    "hint": null,
+                    "default": "light",
+                },
+            },
+            "addresses": [{
+                "country": {
+                    "$": "optional",
+                    "type": { "?": "string", "label": "Country", "hint": null, "default": "NL" },
+                },
+            }],
+        }));
+
+        let mut value = json!({
+            "name": "Alice",
+            "settings": {},
+            "addresses": [{ "country": "BE" }, {}],
+        });
+
+        assert_eq!(
+            filled_pointers(&schema_type, &mut value),
+            vec!["/addresses/1/country", "/role", "/settings/theme"]
+        );
+        assert_eq!(value, json!({
+            "name": "Alice",
+            "role": "member",
+            "settings": { "theme": "light" },
+            "addresses": [{ "country": "BE" }, { "country": "NL" }],
+        }));
+    }
+
+    #[test]
+    fn present_and_null_values_are_kept() {
+        let schema_type = parse_schema_type(json!({
+            "role": {
+                "?": { "$": "optional", "type": "string" },
+                "label": "Role",
+                "hint": null,
+                "default": "member",
+            },
+        }));
+
+        let mut value = json!({ "role": null });
+        assert!(schema_type.apply_defaults(&mut value).is_empty());
+        assert_eq!(value, json!({ "role": null }));
+
+        let mut value = json!("not an object");
+        assert!(schema_type.apply_defaults(&mut value).is_empty());
+    }
+
+    #[test]
+    fn defaults_inside_an_inserted_default_are_filled() {
+        let schema_type = parse_schema_type(json!({
+            "settings": {
+                "?": {
+                    "theme": { "?": "string", "label": "Theme", "hint": null, "default": "light" },
+                    "language": { "?": "string", "label": "Language", "hint": null, "default": "en" },
+                },
+                "label": "Settings",
+                "hint": null,
+                "default": { "language": "nl" },
+            },
+        }));
+
+        let mut value = json!({});
+
+        assert_eq!(filled_pointers(&schema_type, &mut value), vec!["/settings", "/settings/theme"]);
+        assert_eq!(value, json!({ "settings": { "language": "nl", "theme": "light" } }));
+    }
+
+    #[test]
+    fn defaults_of_the_matching_variant_and_references_are_used() {
+        let definitions: Definitions = serde_json::from_value(json!({
+            "menu": {
+                "label": "string",
+                "open": { "?": "boolean", "label": "Open", "hint": null, "default": false },
+                "children": { "$": "optional", "type": [{ "$": "ref", "name": "menu" }] },
+            },
+        }))
+            .unwrap();
+
+        let schema_type = parse_schema_type(json!({ "$": "ref", "name": "menu" }));
+        let mut value = json!({ "label": "File", "children": [{ "label": "Open" }] });
+
+        let filled = filled_pointers_in(&schema_type, &mut value, ValidationScope::new(&definitions));
+        assert_eq!(filled, vec!["/children/0/open", "/open"]);
+
+        let schema_type = parse_schema_type(json!({
+            "$": "tagged",
+            "discriminator": "kind",
+            "variants": {
+                "circle": {
+                    "kind": "string",
+                    "radius": { "?": "number", "label": "Radius", "hint": null, "default": 1 },
+                },
+                "square": {
+                    "kind": "string",
+                    "size": { "?": "number", "label": "Size", "hint": null, "default": 2 },
+                },
+            },
+        }));
+
+        let mut value = json!({ "kind": "square" });
+        assert_eq!(filled_pointers(&schema_type, &mut value), vec!["/size"]);
+        assert_eq!(value, json!({ "kind": "square", "size": 2 }));
+    }
+}
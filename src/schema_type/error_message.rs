@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::report::validation_context::ValidationContext;
+use crate::schema_type::advanced_type::AdvancedType;
+use crate::schema_type::SchemaTypeValidationError;
+use crate::traits::error_code::ErrorCode;
+use crate::traits::reporting_validator::ReportingValidator;
+use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
+
+/// A message written for people that replaces the default message when a schema node rejects a
+/// value, like "Please enter your company VAT number". Set through `errorMessage` on a [Field] or
+/// an advanced type. This is either a single message that is used for every error, or a message
+/// per error code, see [ErrorCode]. Errors without a message keep their default message.
+///
+/// The errors keep their code and params, so [ErrorCode::custom_message] can be used to check
+/// for a replaced message. When nested nodes both set a message, the innermost message is used.
+///
+/// [Field]: crate::schema_type::field::Field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ErrorMessage {
+    Single(String),
+    PerCode(HashMap<String, String>),
+}
+
+impl ErrorMessage {
+    /// Returns the message for errors with the given code.
+    pub fn message_for(&self, code: &str) -> Option<&str> {
+        match self {
+            ErrorMessage::Single(message) => Some(message),
+            ErrorMessage::PerCode(messages) => messages.get(code)
+                .map(|message| message.as_str()),
+        }
+    }
+
+    /// Replaces the message of the error, unless a nested node already did.
+    pub(crate) fn apply(&self, error: SchemaTypeValidationError) -> SchemaTypeValidationError {
+        if error.custom_message().is_some() {
+            return error;
+        }
+
+        match self.message_for(error.code()) {
+            Some(message) => SchemaTypeValidationError::CustomMessage(message.to_string(), Box::new(error)),
+            None => error,
+        }
+    }
+
+    /// Reports the errors of the closure with their message replaced.
+    pub(crate) fn report<R>(&self, context: &mut ValidationContext, f: impl FnOnce(&mut ValidationContext) -> R) -> R {
+        context.wrap_errors(f, |error| self.apply(error))
+    }
+}
+
+/// An advanced type with an `errorMessage`, see [ErrorMessage]. This is (de)serialized as the
+/// advanced type with an additional `errorMessage` key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithErrorMessage {
+    #[serde(flatten)]
+    pub kind: AdvancedType,
+
+    pub error_message: ErrorMessage,
+}
+
+impl Display for WithErrorMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.kind, f)
+    }
+}
+
+impl Validator for WithErrorMessage {
+    type E = SchemaTypeValidationError;
+
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
+        self.kind.validate_in(value, scope)
+            .map_err(|error| self.error_message.apply(error.into()))
+    }
+}
+
+impl ReportingValidator for WithErrorMessage {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        self.error_message.report(context, |context| self.kind.report(value, context));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use crate::schema_type::error_message::WithErrorMessage;
+    use crate::schema_type::SchemaType;
+    use crate::traits::error_code::ErrorCode;
+    use crate::traits::reporting_validator::ReportingValidator;
+    use crate::traits::validator::Validator;
+
+    fn parse_schema_type(value: Value) -> SchemaType {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn advanced_types_with_a_message_are_deserialized() {
+        let schema_type = parse_schema_type(json!({
+            "$": "string",
+            "pattern": "^NL[0-9]{9}B[0-9]{2}$",
+            "errorMessage": "Please enter your company VAT number",
+        }));
+
+        assert!(matches!(schema_type, SchemaType::WithErrorMessage(WithErrorMessage { .. })));
+        assert_eq!(serde_json::from_value::<SchemaType>(serde_json::to_value(&schema_type).unwrap()).unwrap(), schema_type);
+
+        let error = schema_type.validate(&json!("NL123")).unwrap_err();
+
+        assert_eq!(error.to_string(), "Please enter your company VAT number");
+        assert_eq!(error.code(), "string.patternMismatch");
+        assert_eq!(error.custom_message(), Some("Please enter your company VAT number"));
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "code": "string.patternMismatch",
+                "params": { "pattern": "^NL[0-9]{9}B[0-9]{2}$" },
+                "message": "Please enter your company VAT number",
+            })
+        );
+    }
+
+    #[test]
+    fn messages_can_be_set_per_error_code() {
+        let schema_type = parse_schema_type(json!({
+            "$": "number",
+            "minimum": 18,
+            "errorMessage": { "number.belowMinimum": "You should be at least 18 years old" },
+        }));
+
+        assert_eq!(schema_type.validate(&json!(12)).unwrap_err().to_string(), "You should be at least 18 years old");
+        assert_eq!(schema_type.validate(&json!("12")).unwrap_err().to_string(), "The provided value is not a number");
+    }
+
+    #[test]
+    fn innermost_message_is_used() {
+        let schema_type = parse_schema_type(json!({
+            "$": "object",
+            "object": {
+                "vat": { "$": "string", "minLength": 5, "errorMessage": "Please enter your company VAT number" },
+                "name": "filledString",
+            },
+            "errorMessage": "Please check the company details",
+        }));
+
+        let report = schema_type.validate_all(&json!({ "vat": "", "name": "" }));
+
+        assert_eq!(report.errors.len(), 2);
+        assert_eq!(report.errors[0].error.to_string(), "Please check the company details");
+        assert_eq!(report.errors[1].instance_path.to_json_pointer(), "/vat");
+        assert_eq!(report.errors[1].error.to_string(), "Please enter your company VAT number");
+    }
+}
@@ -1,16 +1,125 @@
 use std::fmt::{Debug, Display, Formatter};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use crate::schema::SchemaValidationError;
 use crate::schema_type::{SchemaType, SchemaTypeValidationError};
+use crate::schema_type::error_message::ErrorMessage;
+use crate::report::validation_context::ValidationContext;
+use crate::shared::deserialize_some;
+use crate::traits::reporting_validator::ReportingValidator;
 use crate::traits::validator::Validator;
+use crate::schema::validation_scope::ValidationScope;
 
+/// Wraps a schema type with information for people, like the label of a form field or the
+/// description of a property in the API documentation. The metadata does not change how values are
+/// validated.
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Field {
     #[serde(rename = "?")]
     field_type: Box<SchemaType>,
 
     label: String,
     hint: Option<String>,
+
+    /// Longer explanation of the field, for example for documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// Example values, which should all match the field type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    examples: Vec<Value>,
+
+    /// The value to use when none is provided, which should match the field type. A default of
+    /// `null` is different from not having a default.
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    default: Option<Value>,
+
+    /// Text to show in an empty input.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    placeholder: Option<String>,
+
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    deprecated: bool,
+
+    /// The value is managed by the server and should not be provided by clients.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    read_only: bool,
+
+    /// The value can be provided by clients but is never returned, like a password.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    write_only: bool,
+
+    /// Replaces the message of errors for this field, like "Please enter your company VAT number".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error_message: Option<ErrorMessage>,
+}
+
+impl Field {
+    pub(crate) fn field_type(&self) -> &SchemaType {
+        &self.field_type
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn examples(&self) -> &[Value] {
+        &self.examples
+    }
+
+    pub fn default_value(&self) -> Option<&Value> {
+        self.default.as_ref()
+    }
+
+    pub fn placeholder(&self) -> Option<&str> {
+        self.placeholder.as_deref()
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    pub fn is_write_only(&self) -> bool {
+        self.write_only
+    }
+
+    pub fn error_message(&self) -> Option<&ErrorMessage> {
+        self.error_message.as_ref()
+    }
+
+    /// Checks that the default value and all examples match the field type.
+    pub fn check_metadata(&self) -> Result<(), SchemaValidationError> {
+        self.check_metadata_in(ValidationScope::default())
+    }
+
+    /// Checks the default value and examples like [Field::check_metadata], resolving references
+    /// in the field type against the definitions of the provided scope.
+    pub fn check_metadata_in(&self, scope: ValidationScope) -> Result<(), SchemaValidationError> {
+        if let Some(default) = &self.default {
+            self.field_type.validate_in(default, scope)
+                .map_err(|error| SchemaValidationError::InvalidFieldDefault(self.label.to_string(), Box::new(error)))?;
+        }
+
+        for (i, example) in self.examples.iter().enumerate() {
+            self.field_type.validate_in(example, scope)
+                .map_err(|error| SchemaValidationError::InvalidFieldExample(self.label.to_string(), i, Box::new(error)))?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Display for Field {
@@ -23,16 +132,55 @@ impl Display for Field {
 impl Validator for Field {
     type E = SchemaTypeValidationError;
 
-    fn validate(&self, value: &Value) -> Result<(), Self::E> {
-        self.field_type.validate(value)
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
+        let result = self.field_type.validate_in(value, scope);
+
+        match &self.error_message {
+            Some(error_message) => result.map_err(|error| error_message.apply(error)),
+            None => result,
+        }
+    }
+}
+
+impl ReportingValidator for Field {
+    fn report(&self, value: &Value, context: &mut ValidationContext) {
+        let report = |context: &mut ValidationContext| {
+            context.enter_schema("?", |context| self.field_type.report(value, context));
+        };
+
+        match &self.error_message {
+            Some(error_message) => error_message.report(context, report),
+            None => report(context),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use serde_json::json;
-    use crate::schema_type::basic_type::BasicType;
+    use crate::schema::SchemaValidationError;
+    use crate::schema_type::basic_type::{BasicType, BasicTypeValidationError};
     use crate::schema_type::field::Field;
+    use crate::schema_type::SchemaTypeValidationError;
+    use crate::traits::error_code::ErrorCode;
+    use crate::traits::reporting_validator::ReportingValidator;
+    use crate::traits::validator::Validator;
+
+    fn name_field() -> Field {
+        Field {
+            field_type: Box::new(BasicType::String.into()),
+            label: "Name".to_string(),
+            hint: Some("Your name".to_string()),
+            description: None,
+            examples: vec![],
+            default: None,
+            placeholder: None,
+            deprecated: false,
+            read_only: false,
+            write_only: false,
+            error_message: None,
+        }
+    }
 
     #[test]
     fn field_is_deserialized_correctly() {
@@ -43,10 +191,104 @@ mod tests {
         }));
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), Field {
-            field_type: Box::new(BasicType::String.into()),
-            label: "Name".to_string(),
-            hint: Some("Your name".to_string()),
+        assert_eq!(result.unwrap(), name_field());
+    }
+
+    #[test]
+    fn field_metadata_round_trips() {
+        let value = json!({
+            "?": "string",
+            "label": "Name",
+            "hint": "Your name",
+            "description": "The name that is shown on your profile.",
+            "examples": ["Alice", "Bob"],
+            "default": null,
+            "placeholder": "Jane Doe",
+            "deprecated": true,
+            "readOnly": true,
+            "writeOnly": false,
         });
+
+        let field = serde_json::from_value::<Field>(value).unwrap();
+
+        assert_eq!(field.description(), Some("The name that is shown on your profile."));
+        assert_eq!(field.examples(), &[json!("Alice"), json!("Bob")]);
+        assert_eq!(field.default_value(), Some(&json!(null)));
+        assert_eq!(field.placeholder(), Some("Jane Doe"));
+        assert!(field.is_deprecated());
+        assert!(field.is_read_only());
+        assert!(!field.is_write_only());
+
+        assert_eq!(serde_json::to_value(&field).unwrap(), json!({
+            "?": "string",
+            "label": "Name",
+            "hint": "Your name",
+            "description": "The name that is shown on your profile.",
+            "examples": ["Alice", "Bob"],
+            "default": null,
+            "placeholder": "Jane Doe",
+            "deprecated": true,
+            "readOnly": true,
+        }));
+
+        let field = serde_json::from_value::<Field>(json!({ "?": "string", "label": "Name", "hint": null })).unwrap();
+        assert_eq!(field.default_value(), None);
+    }
+
+    #[test]
+    fn default_and_examples_are_checked_against_the_field_type() {
+        let field = Field {
+            default: Some(json!("Alice")),
+            examples: vec![json!("Bob")],
+            ..name_field()
+        };
+
+        assert_eq!(field.check_metadata(), Ok(()));
+
+        let field = Field {
+            default: Some(json!(null)),
+            ..name_field()
+        };
+
+        assert_eq!(
+            field.check_metadata(),
+            Err(SchemaValidationError::InvalidFieldDefault(
+                "Name".to_string(),
+                Box::new(SchemaTypeValidationError::BasicTypeValidationError(
+                    BasicTypeValidationError::IncorrectType(BasicType::String, json!(null))
+                ))
+            ))
+        );
+
+        let field = Field {
+            examples: vec![json!("Bob"), json!(10)],
+            ..name_field()
+        };
+
+        assert!(matches!(
+            field.check_metadata(),
+            Err(SchemaValidationError::InvalidFieldExample(label, 1, _)) if label == "Name"
+        ));
+    }
+
+    #[test]
+    fn error_message_replaces_the_message_of_the_field_type() {
+        let field = serde_json::from_value::<Field>(json!({
+            "?": "filledString",
+            "label": "VAT number",
+            "hint": null,
+            "errorMessage": "Please enter your company VAT number",
+        })).unwrap();
+
+        let error = field.validate(&json!("")).unwrap_err();
+
+        assert_eq!(error.to_string(), "Please enter your company VAT number");
+        assert_eq!(error.code(), "basic.emptyString");
+
+        let report = field.validate_all(&json!(10));
+
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].schema_path.to_json_pointer(), "/?");
+        assert_eq!(report.errors[0].error.to_string(), "Please enter your company VAT number");
     }
 }
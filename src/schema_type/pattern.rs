@@ -0,0 +1,67 @@
+use std::fmt::{Display, Formatter};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Regular expression that is (de)serialized as a plain string using `serde_regex`. Two patterns
+/// are considered equal if their source strings are equal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Pattern(#[serde(with = "serde_regex")] pub Regex);
+
+impl Pattern {
+    /// Returns true if the pattern matches anywhere in the provided string. Use `^` and `$` in the
+    /// pattern to match against the whole string.
+    pub fn is_match(&self, value: &str) -> bool {
+        self.0.is_match(value)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<Regex> for Pattern {
+    fn from(value: Regex) -> Self {
+        Pattern(value)
+    }
+}
+
+impl TryFrom<&str> for Pattern {
+    type Error = regex::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Pattern(Regex::new(value)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::schema_type::pattern::Pattern;
+
+    #[test]
+    fn pattern_is_deserialized_from_a_string() {
+        let pattern: Pattern = serde_json::from_value(json!("^[0-9]{4}[A-Z]{2}$")).unwrap();
+
+        assert!(pattern.is_match("1234AB"));
+        assert!(!pattern.is_match("1234 AB"));
+        assert_eq!(serde_json::to_value(&pattern).unwrap(), json!("^[0-9]{4}[A-Z]{2}$"));
+    }
+
+    #[test]
+    fn invalid_pattern_fails_to_deserialize() {
+        assert!(serde_json::from_value::<Pattern>(json!("[a-z")).is_err());
+    }
+}
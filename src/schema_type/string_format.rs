@@ -0,0 +1,574 @@
+use std::fmt::{Display, Formatter};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use serde_email::is_valid_email;
+use thiserror::Error;
+use uuid::Uuid;
+use serde_json::{json, Map, Value};
+use crate::traits::error_code::{serialize_error_code, to_params, ErrorCode};
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum StringFormatError {
+    #[error("Expected a date, but got '{0}'")]
+    IncorrectDate(String),
+
+    #[error("Expected a date-time, but got '{0}'")]
+    IncorrectDateTime(String),
+
+    #[error("Expected a time, but got '{0}'")]
+    IncorrectTime(String),
+
+    #[error("Expected a duration, but got '{0}'")]
+    IncorrectDuration(String),
+
+    #[error("Expected a URI, but got '{0}'")]
+    IncorrectUri(String),
+
+    #[error("Expected an IP address, but got '{0}'")]
+    IncorrectIp(String),
+
+    #[error("Expected an IPv4 address, but got '{0}'")]
+    IncorrectIpv4(String),
+
+    #[error("Expected an IPv6 address, but got '{0}'")]
+    IncorrectIpv6(String),
+
+    #[error("Expected a hostname, but got '{0}'")]
+    IncorrectHostname(String),
+
+    #[error("Expected a UUID, but got '{0}'")]
+    IncorrectUuid(String),
+
+    #[error("Expected an email, but got '{0}'")]
+    IncorrectEmail(String),
+}
+
+impl ErrorCode for StringFormatError {
+    fn code(&self) -> &'static str {
+        match self {
+            StringFormatError::IncorrectDate(_) => "format.incorrectDate",
+            StringFormatError::IncorrectDateTime(_) => "format.incorrectDateTime",
+            StringFormatError::IncorrectTime(_) => "format.incorrectTime",
+            StringFormatError::IncorrectDuration(_) => "format.incorrectDuration",
+            StringFormatError::IncorrectUri(_) => "format.incorrectUri",
+            StringFormatError::IncorrectIp(_) => "format.incorrectIp",
+            StringFormatError::IncorrectIpv4(_) => "format.incorrectIpv4",
+            StringFormatError::IncorrectIpv6(_) => "format.incorrectIpv6",
+            StringFormatError::IncorrectHostname(_) => "format.incorrectHostname",
+            StringFormatError::IncorrectUuid(_) => "format.incorrectUuid",
+            StringFormatError::IncorrectEmail(_) => "format.incorrectEmail",
+        }
+    }
+
+    fn params(&self) -> Map<String, Value> {
+        let (StringFormatError::IncorrectDate(value)
+        | StringFormatError::IncorrectDateTime(value)
+        | StringFormatError::IncorrectTime(value)
+        | StringFormatError::IncorrectDuration(value)
+        | StringFormatError::IncorrectUri(value)
+        | StringFormatError::IncorrectIp(value)
+        | StringFormatError::IncorrectIpv4(value)
+        | StringFormatError::IncorrectIpv6(value)
+        | StringFormatError::IncorrectHostname(value)
+        | StringFormatError::IncorrectUuid(value)
+        | StringFormatError::IncorrectEmail(value)) = self;
+
+        to_params(json!({ "value": value }))
+    }
+}
+
+serialize_error_code!(StringFormatError);
+
+/// Well-known formats a string can be checked against. All formats are checked without any network
+/// access, so for example a hostname is only checked for its syntax and is not resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StringFormat {
+    /// A full date as described in RFC 3339, like `2023-10-12`.
+    Date,
+
+    /// A date and a time with an offset as described in RFC 3339, like `2023-10-12T14:30:00Z`.
+    DateTime,
+
+    /// A time with an offset as described in RFC 3339, like `14:30:00+02:00`.
+    Time,
+
+    /// A duration as described in ISO 8601, like `P1DT12H` or `PT0.5S`.
+    Duration,
+
+    /// An absolute URI as described in RFC 3986, like `https://example.com/path?query`.
+    Uri,
+
+    /// Either an IPv4 or an IPv6 address.
+    Ip,
+
+    /// An IPv4 address in dotted decimal notation, like `192.168.0.1`.
+    Ipv4,
+
+    /// An IPv6 address, like `::1` or `2001:db8::8a2e:370:7334`.
+    Ipv6,
+
+    /// A hostname as described in RFC 1123, like `api.example.com`.
+    Hostname,
+
+    /// An [Uuid], check [Uuid::parse_str] for more details about formatting.
+    Uuid,
+
+    /// An email address.
+    Email,
+}
+
+impl Display for StringFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let slice = match self {
+            StringFormat::Date => "date",
+            StringFormat::DateTime => "date-time",
+            StringFormat::Time => "time",
+            StringFormat::Duration => "duration",
+            StringFormat::Uri => "uri",
+            StringFormat::Ip => "ip address",
+            StringFormat::Ipv4 => "ipv4 address",
+            StringFormat::Ipv6 => "ipv6 address",
+            StringFormat::Hostname => "hostname",
+            StringFormat::Uuid => "uuid",
+            StringFormat::Email => "email",
+        };
+
+        write!(f, "{}", slice)
+    }
+}
+
+impl StringFormat {
+    /// Checks if the provided string is formatted according to this format.
+    pub fn validate_str(&self, value: &str) -> Result<(), StringFormatError> {
+        let (is_valid, error): (bool, fn(String) -> StringFormatError) = match self {
+            StringFormat::Date => (is_date(value), StringFormatError::IncorrectDate),
+            StringFormat::DateTime => (is_date_time(value), StringFormatError::IncorrectDateTime),
+            StringFormat::Time => (is_time(value), StringFormatError::IncorrectTime),
+            StringFormat::Duration => (is_duration(value), StringFormatError::IncorrectDuration),
+            StringFormat::Uri => (is_uri(value), StringFormatError::IncorrectUri),
+            StringFormat::Ip => (IpAddr::from_str(value).is_ok(), StringFormatError::IncorrectIp),
+            StringFormat::Ipv4 => (Ipv4Addr::from_str(value).is_ok(), StringFormatError::IncorrectIpv4),
+            StringFormat::Ipv6 => (Ipv6Addr::from_str(value).is_ok(), StringFormatError::IncorrectIpv6),
+            StringFormat::Hostname => (is_hostname(value), StringFormatError::IncorrectHostname),
+            StringFormat::Uuid => (Uuid::from_str(value).is_ok(), StringFormatError::IncorrectUuid),
+            StringFormat::Email => (is_valid_email(value), StringFormatError::IncorrectEmail),
+        };
+
+        if !is_valid {
+            return Err(error(value.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a fixed number of ASCII digits. Unlike [str::parse] this does not accept a sign.
+fn parse_digits(value: &str) -> Option<u32> {
+    if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    value.parse().ok()
+}
+
+fn is_leap_year(year: u32) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+/// `full-date` from RFC 3339: `YYYY-MM-DD` where the day should exist in the given month.
+fn is_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if !value.is_ascii() || bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return false;
+    }
+
+    let (Some(year), Some(month), Some(day)) = (
+        parse_digits(&value[0..4]),
+        parse_digits(&value[5..7]),
+        parse_digits(&value[8..10]),
+    ) else {
+        return false;
+    };
+
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => return false,
+    };
+
+    (1..=days_in_month).contains(&day)
+}
+
+/// `full-time` from RFC 3339: `HH:MM:SS`, optional fractional seconds and a required offset which
+/// is either `Z` or `+HH:MM`/`-HH:MM`. A leap second (`60`) is accepted.
+fn is_time(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if !value.is_ascii() || bytes.len() < 9 || bytes[2] != b':' || bytes[5] != b':' {
+        return false;
+    }
+
+    let (Some(hour), Some(minute), Some(second)) = (
+        parse_digits(&value[0..2]),
+        parse_digits(&value[3..5]),
+        parse_digits(&value[6..8]),
+    ) else {
+        return false;
+    };
+
+    if hour > 23 || minute > 59 || second > 60 {
+        return false;
+    }
+
+    let mut rest = &value[8..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.bytes()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+
+        if digits == 0 {
+            return false;
+        }
+
+        rest = &fraction[digits..];
+    }
+
+    if rest.eq_ignore_ascii_case("z") {
+        return true;
+    }
+
+    let Some(offset) = rest.strip_prefix('+').or_else(|| rest.strip_prefix('-')) else {
+        return false;
+    };
+
+    let offset_bytes = offset.as_bytes();
+    if offset_bytes.len() != 5 || offset_bytes[2] != b':' {
+        return false;
+    }
+
+    matches!(
+        (parse_digits(&offset[0..2]), parse_digits(&offset[3..5])),
+        (Some(hour), Some(minute)) if hour <= 23 && minute <= 59
+    )
+}
+
+/// `date-time` from RFC 3339: a [is_date] and [is_time] separated by a `T`.
+fn is_date_time(value: &str) -> bool {
+    let Some((date, time)) = value.split_once(['T', 't']) else {
+        return false;
+    };
+
+    is_date(date) && is_time(time)
+}
+
+/// Durations from ISO 8601 like `P3Y6M4DT12H30M5S` or `P2W`. Each component is optional, but at
+/// least one should be present and the components should be in order. Only the last component can
+/// have a fraction.
+fn is_duration(value: &str) -> bool {
+    let Some(rest) = value.strip_prefix('P') else {
+        return false;
+    };
+
+    let (date, time) = match rest.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (rest, None),
+    };
+
+    let mut components = Vec::new();
+    let date_components = duration_components(date, &['Y', 'M', 'W', 'D'], &mut components);
+    let time_components = match time {
+        Some(time) => duration_components(time, &['H', 'M', 'S'], &mut components),
+        None => Some(0),
+    };
+
+    let (Some(date_components), Some(time_components)) = (date_components, time_components) else {
+        return false;
+    };
+
+    // A `T` should always be followed by at least one time component.
+    if date_components + time_components == 0 || (time.is_some() && time_components == 0) {
+        return false;
+    }
+
+    let fractions = components.iter()
+        .filter(|number| number.contains(['.', ',']))
+        .count();
+
+    fractions == 0 || (fractions == 1 && components.last().is_some_and(|last| last.contains(['.', ','])))
+}
+
+/// Splits a part of a duration into its components, which should use the designators in the given
+/// order. Returns the number of components found, or `None` if the part is malformed.
+fn duration_components<'a>(
+    value: &'a str,
+    designators: &[char],
+    components: &mut Vec<&'a str>,
+) -> Option<usize> {
+    let mut rest = value;
+    let mut remaining_designators = designators;
+    let mut count = 0;
+
+    while !rest.is_empty() {
+        let end = rest.find(|character: char| !character.is_ascii_digit() && character != '.' && character != ',')?;
+        let (number, designator) = (&rest[..end], rest[end..].chars().next()?);
+
+        let position = remaining_designators.iter()
+            .position(|allowed| *allowed == designator)?;
+
+        let is_number = number.split_once(['.', ','])
+            .map_or(parse_digits(number).is_some(), |(whole, fraction)| {
+                parse_digits(whole).is_some() && parse_digits(fraction).is_some()
+            });
+
+        if !is_number {
+            return None;
+        }
+
+        components.push(number);
+        remaining_designators = &remaining_designators[position + 1..];
+        rest = &rest[end + designator.len_utf8()..];
+        count += 1;
+    }
+
+    Some(count)
+}
+
+/// Absolute URIs from RFC 3986: a scheme followed by a colon and only characters that are allowed
+/// in a URI, where every `%` should start a valid percent-encoded byte.
+fn is_uri(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once(':') else {
+        return false;
+    };
+
+    let mut scheme_characters = scheme.chars();
+    let valid_scheme = scheme_characters.next().is_some_and(|first| first.is_ascii_alphabetic())
+        && scheme_characters.all(|character| {
+            character.is_ascii_alphanumeric() || matches!(character, '+' | '-' | '.')
+        });
+
+    if !valid_scheme {
+        return false;
+    }
+
+    let bytes = rest.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let is_encoded = bytes.get(i + 1..i + 3)
+                    .is_some_and(|hex| hex.iter().all(|byte| byte.is_ascii_hexdigit()));
+
+                if !is_encoded {
+                    return false;
+                }
+
+                i += 3;
+            }
+            byte if byte.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'()*+,;=".contains(&byte) => {
+                i += 1;
+            }
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+/// Hostnames from RFC 1123: labels of 1 to 63 letters, digits and hyphens separated by dots, where
+/// a label cannot start or end with a hyphen and the whole name is at most 253 characters.
+fn is_hostname(value: &str) -> bool {
+    let value = value.strip_suffix('.').unwrap_or(value);
+
+    if value.is_empty() || value.len() > 253 {
+        return false;
+    }
+
+    value.split('.').all(|label| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema_type::string_format::{StringFormat, StringFormatError};
+
+    fn assert_valid(format: StringFormat, values: &[&str]) {
+        for value in values {
+            assert_eq!(format.validate_str(value), Ok(()), "'{}' should be a valid {}", value, format);
+        }
+    }
+
+    fn assert_invalid(format: StringFormat, values: &[&str]) {
+        for value in values {
+            assert!(format.validate_str(value).is_err(), "'{}' should not be a valid {}", value, format);
+        }
+    }
+
+    #[test]
+    fn date_is_validated_correctly() {
+        assert_valid(StringFormat::Date, &["2023-10-12", "2024-02-29", "2000-02-29", "0001-01-01"]);
+        assert_invalid(StringFormat::Date, &[
+            "",
+            "2023-13-01",
+            "2023-02-29",
+            "1900-02-29",
+            "2023-04-31",
+            "2023-00-10",
+            "2023-1-10",
+            "23-10-12",
+            "2023/10/12",
+            "2023-10-12T00:00:00Z",
+            "+023-10-12",
+            "2023-10-1é",
+        ]);
+
+        assert_eq!(
+            StringFormat::Date.validate_str("2023-02-30"),
+            Err(StringFormatError::IncorrectDate("2023-02-30".to_string()))
+        );
+    }
+
+    #[test]
+    fn time_is_validated_correctly() {
+        assert_valid(StringFormat::Time, &[
+            "14:30:00Z",
+            "14:30:00z",
+            "23:59:60Z",
+            "14:30:00.123+02:00",
+            "00:00:00-05:30",
+        ]);
+        assert_invalid(StringFormat::Time, &[
+            "14:30:00",
+            "24:00:00Z",
+            "14:60:00Z",
+            "14:30:61Z",
+            "14:30Z",
+            "14:30:00.Z",
+            "14:30:00+2:00",
+            "14:30:00+24:00",
+            "14:30:00 Z",
+            "14:30:00+0é:00",
+        ]);
+    }
+
+    #[test]
+    fn date_time_is_validated_correctly() {
+        assert_valid(StringFormat::DateTime, &[
+            "2023-10-12T14:30:00Z",
+            "2023-10-12t14:30:00.5+01:00",
+        ]);
+        assert_invalid(StringFormat::DateTime, &[
+            "2023-10-12",
+            "2023-10-12T14:30:00",
+            "2023-10-12 14:30:00Z",
+            "2023-02-30T14:30:00Z",
+            "2023-10-12T25:30:00Z",
+        ]);
+    }
+
+    #[test]
+    fn duration_is_validated_correctly() {
+        assert_valid(StringFormat::Duration, &[
+            "P3Y6M4DT12H30M5S",
+            "P1D",
+            "PT1H",
+            "PT0.5S",
+            "P2W",
+            "P1M",
+            "PT1M",
+            "P0,5Y",
+        ]);
+        assert_invalid(StringFormat::Duration, &[
+            "",
+            "P",
+            "PT",
+            "P1DT",
+            "1D",
+            "P1H",
+            "PT1D",
+            "P1D2Y",
+            "P1.5DT1H",
+            "P-1D",
+            "P1.D",
+            "p1d",
+        ]);
+    }
+
+    #[test]
+    fn uri_is_validated_correctly() {
+        assert_valid(StringFormat::Uri, &[
+            "https://example.com",
+            "https://example.com/path?query=1&other=two#fragment",
+            "mailto:alice@example.com",
+            "urn:isbn:0451450523",
+            "https://example.com/caf%C3%A9",
+            "https://[::1]:8080/",
+        ]);
+        assert_invalid(StringFormat::Uri, &[
+            "",
+            "example.com",
+            "/relative/path",
+            "https://example.com/with space",
+            "https://example.com/%zz",
+            "https://example.com/%2",
+            "1http://example.com",
+            "https://example.com/café",
+        ]);
+    }
+
+    #[test]
+    fn ip_addresses_are_validated_correctly() {
+        assert_valid(StringFormat::Ip, &["192.168.0.1", "::1"]);
+        assert_valid(StringFormat::Ipv4, &["192.168.0.1", "0.0.0.0", "255.255.255.255"]);
+        assert_valid(StringFormat::Ipv6, &["::1", "2001:db8::8a2e:370:7334", "::ffff:192.168.0.1"]);
+
+        assert_invalid(StringFormat::Ip, &["", "example.com", "192.168.0"]);
+        assert_invalid(StringFormat::Ipv4, &["::1", "256.0.0.1", "192.168.0", "192.168.0.1.2"]);
+        assert_invalid(StringFormat::Ipv6, &["192.168.0.1", "2001:db8::g", ":::1"]);
+
+        assert_eq!(
+            StringFormat::Ipv4.validate_str("::1"),
+            Err(StringFormatError::IncorrectIpv4("::1".to_string()))
+        );
+    }
+
+    #[test]
+    fn hostname_is_validated_correctly() {
+        assert_valid(StringFormat::Hostname, &[
+            "localhost",
+            "api.example.com",
+            "example.com.",
+            "xn--caf-dma.example",
+            "1password.com",
+            &["a"; 127].join("."),
+        ]);
+        assert_invalid(StringFormat::Hostname, &[
+            "",
+            ".",
+            "-example.com",
+            "example-.com",
+            "example..com",
+            "exa_mple.com",
+            "example.com/path",
+            &"a".repeat(64),
+            &["a"; 128].join("."),
+        ]);
+    }
+
+    #[test]
+    fn formats_are_deserialized_in_camel_case() {
+        let format: StringFormat = serde_json::from_str("\"dateTime\"").unwrap();
+        assert_eq!(format, StringFormat::DateTime);
+
+        let format: StringFormat = serde_json::from_str("\"ipv6\"").unwrap();
+        assert_eq!(format, StringFormat::Ipv6);
+    }
+}
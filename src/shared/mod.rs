@@ -1,5 +1,179 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use serde::{Deserialize, Deserializer};
+use serde::de::Error as DeError;
+use serde_json::{Number, Value};
+
 /// Helper function to let Serde set a default value of `true`. Check this
 /// [GitHub issue](https://github.com/serde-rs/serde/issues/368) for more information.
 pub(crate) fn default_true() -> bool {
     true
 }
+
+/// Helper function to deserialize a value that is present as `Some`, even if it is `null`. Combine
+/// this with `#[serde(default)]` so a missing key results in `None`.
+pub(crate) fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// Checks that a path is a JSON Pointer, which is either empty or starts with a `/`. Paths like
+/// `sku` would never match anything, so they are rejected when the schema is deserialized.
+pub(crate) fn check_json_pointer<E: DeError>(pointer: &str) -> Result<(), E> {
+    if !pointer.is_empty() && !pointer.starts_with('/') {
+        return Err(E::custom(format!("'{0}' is not a JSON Pointer, use '/{0}' to refer to a key", pointer)));
+    }
+
+    Ok(())
+}
+
+/// Compares two JSON values for equality, where numbers are compared by their numeric value so
+/// `1` and `1.0` are considered equal.
+pub(crate) fn json_equals(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => numbers_equal(left, right),
+        (Value::Array(left), Value::Array(right)) => {
+            left.len() == right.len()
+                && left.iter().zip(right).all(|(left, right)| json_equals(left, right))
+        }
+        (Value::Object(left), Value::Object(right)) => {
+            left.len() == right.len()
+                && left.iter().all(|(key, left)| {
+                    right.get(key).is_some_and(|right| json_equals(left, right))
+                })
+        }
+        (left, right) => left == right,
+    }
+}
+
+fn numbers_equal(left: &Number, right: &Number) -> bool {
+    left == right || compare_numbers(left, right) == Some(Ordering::Equal)
+}
+
+/// Compares two numbers by their numeric value. Integers are compared exactly, as integers above
+/// 2^53 cannot all be represented as a float. An integer is compared against a float without
+/// converting the integer, so `9007199254740993` is greater than `9007199254740992.0`. Only two
+/// floats are compared as floats.
+pub(crate) fn compare_numbers(left: &Number, right: &Number) -> Option<Ordering> {
+    match (as_integer(left), as_integer(right)) {
+        (Some(left), Some(right)) => Some(left.cmp(&right)),
+        (Some(left), None) => compare_integer_to_float(left, right.as_f64()?),
+        (None, Some(right)) => compare_integer_to_float(right, left.as_f64()?).map(Ordering::reverse),
+        (None, None) => left.as_f64()?.partial_cmp(&right.as_f64()?),
+    }
+}
+
+fn as_integer(number: &Number) -> Option<i128> {
+    number.as_i64()
+        .map(i128::from)
+        .or_else(|| number.as_u64().map(i128::from))
+}
+
+fn compare_integer_to_float(integer: i128, float: f64) -> Option<Ordering> {
+    if float.is_nan() {
+        return None;
+    }
+
+    // Every i64 and u64 is within the range of an i128, so floats outside of it are greater or
+    // less than any integer. Floats within the range are converted exactly once rounded down.
+    let floor = float.floor();
+    if floor >= 2_f64.powi(127) {
+        return Some(Ordering::Less);
+    }
+
+    if floor < -(2_f64.powi(127)) {
+        return Some(Ordering::Greater);
+    }
+
+    match integer.cmp(&(floor as i128)) {
+        Ordering::Equal if float != floor => Some(Ordering::Less),
+        ordering => Some(ordering),
+    }
+}
+
+/// Looks up a value by JSON Pointer, like [Value::pointer]. Unlike [Value::pointer], this only
+/// allocates for tokens that contain escapes, so it can be used while validating large payloads.
+pub(crate) fn json_pointer<'a>(value: &'a Value, pointer: &str) -> Option<&'a Value> {
+    if pointer.is_empty() {
+        return Some(value);
+    }
+
+    pointer.strip_prefix('/')?
+        .split('/')
+        .try_fold(value, |target, token| {
+            let token = match token.contains('~') {
+                true => Cow::Owned(token.replace("~1", "/").replace("~0", "~")),
+                false => Cow::Borrowed(token),
+            };
+
+            match target {
+                Value::Object(map) => map.get(token.as_ref()),
+                Value::Array(items) => parse_index(&token).and_then(|index| items.get(index)),
+                _ => None,
+            }
+        })
+}
+
+/// Parses an array index of a JSON Pointer, which cannot have a sign or leading zeros.
+fn parse_index(token: &str) -> Option<usize> {
+    if token.starts_with('+') || (token.starts_with('0') && token.len() != 1) {
+        return None;
+    }
+
+    token.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use serde_json::{json, Value};
+    use crate::shared::{compare_numbers, json_equals, json_pointer};
+
+    #[test]
+    fn large_integers_are_compared_exactly() {
+        assert!(json_equals(&json!(9007199254740993_u64), &json!(9007199254740993_u64)));
+        assert!(!json_equals(&json!(9007199254740993_u64), &json!(9007199254740992_u64)));
+        assert!(!json_equals(&json!(-9007199254740993_i64), &json!(-9007199254740992_i64)));
+        assert!(!json_equals(&json!(u64::MAX), &json!(-1)));
+        assert!(json_equals(&json!(1), &json!(1.0)));
+        assert!(json_equals(&json!([2, { "a": -3 }]), &json!([2.0, { "a": -3.0 }])));
+        assert!(!json_equals(&json!(1), &json!(1.5)));
+        assert!(!json_equals(&json!(9007199254740993_u64), &json!(9007199254740992.0)));
+        assert!(!json_equals(&json!(9007199254740992.0), &json!(9007199254740993_u64)));
+        assert!(json_equals(&json!(9007199254740992_u64), &json!(9007199254740992.0)));
+        assert!(!json_equals(&json!(u64::MAX), &json!(18446744073709551615.0)));
+    }
+
+    #[test]
+    fn integers_are_compared_exactly_against_floats() {
+        let compare = |left: Value, right: Value| compare_numbers(left.as_number().unwrap(), right.as_number().unwrap());
+
+        assert_eq!(compare(json!(9007199254740993_u64), json!(9007199254740992.0)), Some(Ordering::Greater));
+        assert_eq!(compare(json!(9007199254740992.0), json!(9007199254740993_u64)), Some(Ordering::Less));
+        assert_eq!(compare(json!(-9007199254740993_i64), json!(-9007199254740992.0)), Some(Ordering::Less));
+        assert_eq!(compare(json!(2), json!(2.5)), Some(Ordering::Less));
+        assert_eq!(compare(json!(-3), json!(-2.5)), Some(Ordering::Less));
+        assert_eq!(compare(json!(-2), json!(-2.5)), Some(Ordering::Greater));
+        assert_eq!(compare(json!(u64::MAX), json!(1e300)), Some(Ordering::Less));
+        assert_eq!(compare(json!(i64::MIN), json!(-1e300)), Some(Ordering::Greater));
+        assert_eq!(compare(json!(1.5), json!(2.5)), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn json_pointer_matches_value_pointer() {
+        let value = json!({
+            "a/b": { "~c": [1, 2, { "d": null }] },
+            "": "empty",
+            "list": ["x", "y"],
+        });
+
+        let pointers = ["", "/", "/a~1b", "/a~1b/~0c/2/d", "/a~1b/~0c/02", "/a~1b/~0c/+1", "/list/1", "/list/2", "list", "/missing/key"];
+
+        for pointer in pointers {
+            assert_eq!(json_pointer(&value, pointer), value.pointer(pointer), "{}", pointer);
+        }
+    }
+}
@@ -1 +1,4 @@
 pub mod validator;
+pub mod reporting_validator;
+pub mod custom_validator;
+pub mod error_code;
@@ -0,0 +1,20 @@
+use std::error::Error;
+use std::fmt::Debug;
+use serde_json::Value;
+
+/// Validator that can be implemented outside of this crate and used in a schema through a
+/// [CustomType]. Implementations are registered using `#[typetag::serde]` and are (de)serialized
+/// with the registered name in the `validator` key, so an implementation registered as `iban` is
+/// used in a schema as `{ "$": "custom", "validator": "iban" }`. Any fields of the implementation
+/// are (de)serialized next to it.
+///
+/// [CustomType]: crate::schema_type::advanced_type::custom_type::CustomType
+#[typetag::serde(tag = "validator")]
+pub trait CustomValidator: Debug + Send + Sync {
+    fn validate(&self, value: &Value) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Short description of what the validator checks, used when displaying a schema.
+    fn description(&self) -> String {
+        "custom validator".to_string()
+    }
+}
@@ -0,0 +1,147 @@
+use serde_json::{Map, Value};
+
+/// Machine-readable description of an error, so clients can handle errors without matching the
+/// English messages. Codes are made up of the kind of schema that rejected the value and the
+/// reason, like `string.tooLong`, and stay the same between releases. The params hold the values
+/// that are used in the message, like the maximum length.
+///
+/// Errors that only wrap another error, like [SchemaTypeValidationError], use the code and params of
+/// the wrapped error. Errors that add context to another error, like the condition of an
+/// [IfType], include the serialized wrapped error as the `error` param.
+///
+/// All error types that implement this trait serialize as `{ "code": ..., "params": { ... } }`,
+/// with an additional `message` when the message was replaced by the schema, see [ErrorMessage].
+///
+/// [SchemaTypeValidationError]: crate::schema_type::SchemaTypeValidationError
+/// [IfType]: crate::schema_type::advanced_type::if_type::IfType
+/// [ErrorMessage]: crate::schema_type::error_message::ErrorMessage
+pub trait ErrorCode {
+    fn code(&self) -> &'static str;
+
+    fn params(&self) -> Map<String, Value> {
+        Map::new()
+    }
+
+    /// The message that was set in the schema for this error, which replaces the default message.
+    fn custom_message(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Turns the output of `json!` into params, expecting an object.
+pub(crate) fn to_params(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(params) => params,
+        _ => Map::new(),
+    }
+}
+
+/// Implements [serde::Serialize] for error types that implement [ErrorCode].
+macro_rules! serialize_error_code {
+    ($($error:ty),+ $(,)?) => {
+        $(
+            impl serde::Serialize for $error {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    use serde::ser::SerializeStruct;
+                    use $crate::traits::error_code::ErrorCode;
+
+                    let mut state = serializer.serialize_struct(stringify!($error), 3)?;
+                    state.serialize_field("code", self.code())?;
+                    state.serialize_field("params", &self.params())?;
+
+                    match self.custom_message() {
+                        Some(message) => state.serialize_field("message", message)?,
+                        None => state.skip_field("message")?,
+                    }
+
+                    state.end()
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use serialize_error_code;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use crate::schema_type::basic_type::BasicType;
+    use crate::schema_type::SchemaType;
+    use crate::traits::error_code::ErrorCode;
+    use crate::traits::reporting_validator::ReportingValidator;
+    use crate::traits::validator::Validator;
+
+    fn parse_schema_type(value: Value) -> SchemaType {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn wrapping_errors_use_the_code_of_the_wrapped_error() {
+        let schema_type = parse_schema_type(json!({ "$": "string", "maxLength": 3 }));
+        let error = schema_type.validate(&json!("Alice")).unwrap_err();
+
+        assert_eq!(error.code(), "string.tooLong");
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({ "code": "string.tooLong", "params": { "length": 5, "max": 3 } })
+        );
+
+        let error = SchemaType::from(BasicType::Date)
+            .validate(&json!("2024-13-01"))
+            .unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({ "code": "format.incorrectDate", "params": { "value": "2024-13-01" } })
+        );
+    }
+
+    #[test]
+    fn errors_with_context_include_the_wrapped_error() {
+        let schema_type = parse_schema_type(json!({
+            "$": "if",
+            "if": { "country": { "$": "const", "value": "US" } },
+            "then": { "state": "string" },
+        }));
+
+        let error = schema_type.validate(&json!({ "country": "US" })).unwrap_err();
+
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "code": "if.then",
+                "params": {
+                    "condition": "'country' is \"US\"",
+                    "error": { "code": "object.missingKey", "params": { "key": "state" } },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn report_is_serialized_with_paths() {
+        let schema_type = parse_schema_type(json!({
+            "name": "filledString",
+            "age": "u8",
+            "tags": ["string"],
+        }));
+
+        let report = schema_type.validate_all(&json!({ "name": "", "age": 300, "tags": ["a", 1] }));
+
+        assert_eq!(
+            serde_json::to_value(&report).unwrap(),
+            json!({
+                "errors": [
+                    { "code": "basic.notU8", "path": "/age", "params": { "value": 300 } },
+                    { "code": "basic.emptyString", "path": "/name", "params": {} },
+                    {
+                        "code": "basic.incorrectType",
+                        "path": "/tags/1",
+                        "params": { "expected": "string", "value": 1 },
+                    },
+                ],
+            })
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use serde_json::Value;
+use crate::report::validation_context::ValidationContext;
+use crate::report::{ReportedError, ValidationReport};
+use crate::report::validation_path::ValidationPath;
+use crate::schema::validation_scope::ValidationScope;
+use crate::schema_type::SchemaTypeValidationError;
+use crate::traits::validator::Validator;
+
+/// Collect-all counterpart of [crate::traits::validator::Validator]. Instead of returning on the
+/// first failure, implementations walk the complete value and record every error they find in the
+/// provided [ValidationContext].
+pub trait ReportingValidator {
+    fn report(&self, value: &Value, context: &mut ValidationContext);
+
+    /// Validates the complete value and returns a report with all the errors that were found.
+    fn validate_all(&self, value: &Value) -> ValidationReport {
+        self.validate_all_in(value, ValidationScope::default())
+    }
+
+    /// Validates the complete value like [ReportingValidator::validate_all], resolving references
+    /// against the definitions of the provided scope.
+    fn validate_all_in(&self, value: &Value, scope: ValidationScope) -> ValidationReport {
+        let mut context = ValidationContext::new();
+        context.enter_scope(scope, |context| self.report(value, context));
+
+        context.into_report()
+    }
+
+    /// Validates the value like [Validator::validate], but returns the first error that
+    /// [ReportingValidator::validate_all] would report, which includes the instance and schema
+    /// path where it happened. Values that pass are only validated fail-fast.
+    fn validate_first(&self, value: &Value) -> Result<(), Box<ReportedError>>
+    where
+        Self: Validator,
+        Self::E: Into<SchemaTypeValidationError>,
+    {
+        self.validate_first_in(value, ValidationScope::default())
+    }
+
+    /// Validates the value like [ReportingValidator::validate_first], resolving references
+    /// against the definitions of the provided scope.
+    fn validate_first_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Box<ReportedError>>
+    where
+        Self: Validator,
+        Self::E: Into<SchemaTypeValidationError>,
+    {
+        let Err(error) = self.validate_in(value, scope) else {
+            return Ok(());
+        };
+
+        // The value is walked again to find where the first error is, which is reported at the
+        // root if the walk does not find it.
+        let mut context = ValidationContext::first_error();
+        context.enter_scope(scope, |context| self.report(value, context));
+
+        let first = context.into_report().errors.into_iter().next();
+
+        Err(Box::new(first.unwrap_or_else(|| ReportedError {
+            instance_path: ValidationPath::default(),
+            schema_path: ValidationPath::default(),
+            error: error.into(),
+        })))
+    }
+}
@@ -1,8 +1,31 @@
 use serde_json::Value;
 use std::error::Error;
+use crate::schema::validation_scope::ValidationScope;
 
+/// Implementations provide at least one of [Validator::validate] and [Validator::validate_in].
+/// Types that contain other schemas implement [Validator::validate_in] and pass the scope on to
+/// them, so references inside them can be resolved.
 pub trait Validator {
     type E: Error;
 
-    fn validate(&self, value: &Value) -> Result<(), Self::E>;
+    /// Validates the value and stops at the first failure. The returned error only describes what
+    /// went wrong, not where it happened: use [ReportingValidator::validate_first] to get the
+    /// instance and schema path of the first error, or [ReportingValidator::validate_all] to get
+    /// those of every error.
+    ///
+    /// References are resolved in the default [ValidationScope], which has no definitions, use
+    /// [Validator::validate_in] to provide them.
+    ///
+    /// [ReportingValidator::validate_first]: crate::traits::reporting_validator::ReportingValidator::validate_first
+    /// [ReportingValidator::validate_all]: crate::traits::reporting_validator::ReportingValidator::validate_all
+    fn validate(&self, value: &Value) -> Result<(), Self::E> {
+        self.validate_in(value, ValidationScope::default())
+    }
+
+    /// Validates the value like [Validator::validate], resolving references against the
+    /// definitions of the provided scope.
+    fn validate_in(&self, value: &Value, scope: ValidationScope) -> Result<(), Self::E> {
+        let _ = scope;
+        self.validate(value)
+    }
 }